@@ -0,0 +1,208 @@
+use super::point::is_ccw;
+use super::{Point2, EPSILON};
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Circle {
+    center: Point2,
+    radius: f64,
+}
+
+impl Circle {
+    pub fn new(center: Point2, radius: f64) -> Self {
+        Circle {
+            center,
+            radius: radius.abs(),
+        }
+    }
+
+    // 以 pt_1, pt_2 为直径
+    pub fn from_diameter(pt_1: &Point2, pt_2: &Point2) -> Self {
+        Circle {
+            center: Point2::new((pt_1.x() + pt_2.x()) / 2., (pt_1.y() + pt_2.y()) / 2.),
+            radius: pt_1.euclid_distance(pt_2) / 2.,
+        }
+    }
+
+    // 外接圆, 三点共线时返回 None
+    pub fn circumcircle(pt_a: &Point2, pt_b: &Point2, pt_c: &Point2) -> Option<Self> {
+        if !is_ccw(pt_a, pt_b, pt_c) && !is_ccw(pt_a, pt_c, pt_b) {
+            return None;
+        }
+        let ab = pt_b.from(pt_a);
+        let ac = pt_c.from(pt_a);
+        let d = 2. * ab.exterior(&ac);
+        let (b2, c2) = (ab.square_l2_norm(), ac.square_l2_norm());
+        let x = (ac.y() * b2 - ab.y() * c2) / d;
+        let y = (ab.x() * c2 - ac.x() * b2) / d;
+        Some(Circle {
+            center: Point2::new(pt_a.x() + x, pt_a.y() + y),
+            radius: x.hypot(y),
+        })
+    }
+
+    pub fn center(&self) -> &Point2 {
+        &self.center
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    pub fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    pub fn perimeter(&self) -> f64 {
+        2. * std::f64::consts::PI * self.radius
+    }
+
+    // 含边界
+    pub fn contains(&self, pt: &Point2) -> bool {
+        self.center.euclid_distance(pt) <= self.radius + EPSILON
+    }
+
+    pub fn contains_circle(&self, other: &Circle) -> bool {
+        self.center.euclid_distance(&other.center) + other.radius <= self.radius + EPSILON
+    }
+
+    pub fn intersects_circle(&self, other: &Circle) -> bool {
+        self.center.euclid_distance(&other.center) <= self.radius + other.radius + EPSILON
+    }
+
+    // 线段与圆盘是否相交
+    pub fn intersects_segment(&self, start: &Point2, end: &Point2) -> bool {
+        let d = end.from(start);
+        let len2 = d.square_l2_norm();
+        let t = if len2 < EPSILON * EPSILON {
+            0.
+        } else {
+            (self.center.from(start).dot(&d) / len2).clamp(0., 1.)
+        };
+        self.contains(&start.move_along(&(d * t)))
+    }
+
+    // 线段与圆周的交点, 按从 start 到 end 的顺序, 相切时只有一个
+    pub fn segment_intersection(&self, start: &Point2, end: &Point2) -> Vec<Point2> {
+        let d = end.from(start);
+        let f = start.from(&self.center);
+        let a = d.square_l2_norm();
+        if a < EPSILON * EPSILON {
+            return vec![];
+        }
+        let b = f.dot(&d);
+        let c = f.square_l2_norm() - self.radius * self.radius;
+        let disc = b * b - a * c;
+        // 切点到圆心的距离误差不超过 EPSILON
+        if disc < -2. * self.radius * EPSILON * a {
+            return vec![];
+        }
+        let root = disc.max(0.).sqrt();
+        let tol = EPSILON / a.sqrt();
+        let mut ts = vec![(-b - root) / a];
+        if root * 2. / a.sqrt() > EPSILON {
+            ts.push((-b + root) / a);
+        }
+        ts.into_iter()
+            .filter(|t| *t >= -tol && *t <= 1. + tol)
+            .map(|t| start.move_along(&(d.clone() * t.clamp(0., 1.))))
+            .collect()
+    }
+}
+
+impl fmt::Display for Circle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Circle: [{}, {}]", self.center, self.radius)
+    }
+}
+
+// Welzl 最小覆盖圆, 打乱顺序后期望 O(n)
+pub fn min_enclosing_circle(pts: &[Point2]) -> Option<Circle> {
+    let mut pts = pts.to_vec();
+    // 固定种子的 xorshift, 保证结果可重复
+    let mut seed: u64 = 0x9e37_79b9_7f4a_7c15;
+    for i in (1..pts.len()).rev() {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        pts.swap(i, (seed % (i as u64 + 1)) as usize);
+    }
+
+    let mut circle = Circle::new(pts.first()?.clone(), 0.);
+    for i in 1..pts.len() {
+        if circle.contains(&pts[i]) {
+            continue;
+        }
+        // pts[i] 在圆周上
+        circle = Circle::new(pts[i].clone(), 0.);
+        for j in 0..i {
+            if circle.contains(&pts[j]) {
+                continue;
+            }
+            // pts[i], pts[j] 在圆周上
+            circle = Circle::from_diameter(&pts[i], &pts[j]);
+            for k in 0..j {
+                if circle.contains(&pts[k]) {
+                    continue;
+                }
+                circle = match Circle::circumcircle(&pts[i], &pts[j], &pts[k]) {
+                    Some(c) => c,
+                    // 共线时取距离最远的两点为直径
+                    None => [(&pts[i], &pts[j]), (&pts[i], &pts[k]), (&pts[j], &pts[k])]
+                        .iter()
+                        .map(|(a, b)| Circle::from_diameter(a, b))
+                        .fold(circle, |c, d| if d.radius > c.radius { d } else { c }),
+                };
+            }
+        }
+    }
+    Some(circle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{min_enclosing_circle, Circle};
+    use crate::base::Point2;
+
+    #[test]
+    fn test_circle() {
+        let circle = Circle::new(Point2::new(0., 0.), 1.);
+        assert!(circle.contains(&Point2::new(1., 0.)));
+        assert!(!circle.contains(&Point2::new(1., 1.)));
+
+        let res = circle.segment_intersection(&Point2::new(-2., 0.), &Point2::new(2., 0.));
+        assert_eq!(res, vec![Point2::new(-1., 0.), Point2::new(1., 0.)]);
+        let res = circle.segment_intersection(&Point2::new(0., 0.), &Point2::new(2., 0.));
+        assert_eq!(res, vec![Point2::new(1., 0.)]);
+        // 相切
+        let res = circle.segment_intersection(&Point2::new(-2., 1.), &Point2::new(2., 1.));
+        assert_eq!(res.len(), 1);
+        assert!(circle.intersects_segment(&Point2::new(-0.5, 0.), &Point2::new(0.5, 0.)));
+        assert!(!circle.intersects_segment(&Point2::new(-2., 2.), &Point2::new(2., 2.)));
+    }
+
+    #[test]
+    fn test_min_enclosing_circle() {
+        assert_eq!(min_enclosing_circle(&[]), None);
+        let pts: Vec<Point2> = vec![(0., 0.), (4., 0.), (2., 1.), (1., -1.), (2., 0.)]
+            .into_iter()
+            .map(|(x, y)| Point2::new(x, y))
+            .collect();
+        let circle = min_enclosing_circle(&pts).unwrap();
+        assert!(circle.center().euclid_distance(&Point2::new(2., 0.)) < 1e-9);
+        assert!((circle.radius() - 2.).abs() < 1e-9);
+
+        // 等边三角形的外接圆
+        let pts = vec![
+            Point2::new(0., 0.),
+            Point2::new(2., 0.),
+            Point2::new(1., 3f64.sqrt()),
+            Point2::new(1., 0.5),
+        ];
+        let circle = min_enclosing_circle(&pts).unwrap();
+        assert!((circle.radius() - 2. / 3f64.sqrt()).abs() < 1e-9);
+        assert!(pts.iter().all(|pt| circle.contains(pt)));
+    }
+}
@@ -0,0 +1,365 @@
+use super::{Point2, Vector2, EPSILON};
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+// 线段 start -> end, 参数范围 [0, 1]
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment2 {
+    start: Point2,
+    end: Point2,
+}
+
+// 射线, 从 origin 出发沿 direction, 参数范围 [0, +∞)
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ray2 {
+    origin: Point2,
+    direction: Vector2,
+}
+
+// 直线, 过 point 沿 direction, 参数范围 (-∞, +∞)
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line2 {
+    point: Point2,
+    direction: Vector2,
+}
+
+// 两个线性对象的交: 一个点, 或者重叠的部分 (线段, 射线或直线)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Intersection {
+    None,
+    Point(Point2),
+    Segment(Segment2),
+    Ray(Ray2),
+    Line(Line2),
+}
+
+// 线段, 射线, 直线统一表示为 origin + t * direction, t 在 range 内
+pub trait Linear {
+    fn origin(&self) -> &Point2;
+
+    fn direction(&self) -> Vector2;
+
+    fn range(&self) -> (f64, f64);
+
+    // 参数 t 对应的点, 不检查是否在范围内
+    fn point_at(&self, t: f64) -> Point2 {
+        self.origin().move_along(&(self.direction() * t))
+    }
+
+    // pt 在所在直线上的投影的参数
+    fn param_of(&self, pt: &Point2) -> f64 {
+        let d = self.direction();
+        let len2 = d.square_l2_norm();
+        if len2 < EPSILON * EPSILON {
+            return 0.;
+        }
+        pt.from(self.origin()).dot(&d) / len2
+    }
+
+    // 对象上离 pt 最近的点
+    fn project(&self, pt: &Point2) -> Point2 {
+        let (lo, hi) = self.range();
+        self.point_at(self.param_of(pt).clamp(lo, hi))
+    }
+
+    fn distance_to(&self, pt: &Point2) -> f64 {
+        self.project(pt).euclid_distance(pt)
+    }
+
+    fn contains(&self, pt: &Point2) -> bool {
+        self.distance_to(pt) < EPSILON
+    }
+
+    // 平行且共线时返回重叠部分, 只在端点处接触时返回点
+    fn intersection<T: Linear + ?Sized>(&self, other: &T) -> Intersection {
+        let (o1, d1) = (self.origin(), self.direction());
+        let (o2, d2) = (other.origin(), other.direction());
+        let (lo1, hi1) = self.range();
+        let (lo2, hi2) = other.range();
+        let (len1, len2) = (d1.l2_norm(), d2.l2_norm());
+        if len1 < EPSILON {
+            // 退化为点
+            return if other.contains(o1) {
+                Intersection::Point(o1.clone())
+            } else {
+                Intersection::None
+            };
+        }
+        if len2 < EPSILON {
+            return if self.contains(o2) {
+                Intersection::Point(o2.clone())
+            } else {
+                Intersection::None
+            };
+        }
+
+        let denom = d1.exterior(&d2);
+        let w = o2.from(o1);
+        let (tol1, tol2) = (EPSILON / len1, EPSILON / len2);
+        if denom.abs() > EPSILON * len1 * len2 {
+            let t = w.exterior(&d2) / denom;
+            let u = w.exterior(&d1) / denom;
+            if t < lo1 - tol1 || t > hi1 + tol1 || u < lo2 - tol2 || u > hi2 + tol2 {
+                return Intersection::None;
+            }
+            return Intersection::Point(self.point_at(t.clamp(lo1, hi1)));
+        }
+        // 平行但不共线
+        if w.exterior(&d1).abs() / len1 >= EPSILON {
+            return Intersection::None;
+        }
+
+        // other 的参数范围换算到 self 的参数上, 端点直接取原来的点, 避免舍入误差
+        let t0 = self.param_of(o2);
+        let scale = d2.dot(&d1) / (len1 * len1);
+        let bound = |u: f64| {
+            if u.is_infinite() {
+                (u * scale.signum(), None)
+            } else {
+                (t0 + scale * u, Some(other.point_at(u)))
+            }
+        };
+        let (mut a, mut b) = (bound(lo2), bound(hi2));
+        if a.0 > b.0 {
+            std::mem::swap(&mut a, &mut b);
+        }
+        let lo = if a.0 > lo1 {
+            a
+        } else {
+            (lo1, lo1.is_finite().then(|| self.point_at(lo1)))
+        };
+        let hi = if b.0 < hi1 {
+            b
+        } else {
+            (hi1, hi1.is_finite().then(|| self.point_at(hi1)))
+        };
+        if hi.0 < lo.0 - tol1 {
+            return Intersection::None;
+        }
+        match (lo.1, hi.1) {
+            (Some(p), Some(q)) => {
+                if hi.0 - lo.0 <= tol1 {
+                    Intersection::Point(p)
+                } else {
+                    Intersection::Segment(Segment2::new(p, q))
+                }
+            }
+            (Some(p), None) => Intersection::Ray(Ray2::new(p, d1)),
+            (None, Some(q)) => Intersection::Ray(Ray2::new(q, -d1)),
+            (None, None) => Intersection::Line(Line2::new(o1.clone(), d1)),
+        }
+    }
+}
+
+impl Segment2 {
+    pub fn new(start: Point2, end: Point2) -> Self {
+        Segment2 { start, end }
+    }
+
+    pub fn start(&self) -> &Point2 {
+        &self.start
+    }
+
+    pub fn end(&self) -> &Point2 {
+        &self.end
+    }
+
+    pub fn vector(&self) -> Vector2 {
+        self.end.from(&self.start)
+    }
+
+    pub fn length(&self) -> f64 {
+        self.start.euclid_distance(&self.end)
+    }
+
+    pub fn midpoint(&self) -> Point2 {
+        self.point_at(0.5)
+    }
+
+    pub fn reverse(&self) -> Segment2 {
+        Segment2::new(self.end.clone(), self.start.clone())
+    }
+
+    pub fn line(&self) -> Line2 {
+        Line2::new(self.start.clone(), self.vector())
+    }
+}
+
+impl Ray2 {
+    pub fn new(origin: Point2, direction: Vector2) -> Self {
+        Ray2 { origin, direction }
+    }
+
+    // 从 origin 指向 pt
+    pub fn through(origin: Point2, pt: &Point2) -> Self {
+        let direction = pt.from(&origin);
+        Ray2 { origin, direction }
+    }
+
+    pub fn line(&self) -> Line2 {
+        Line2::new(self.origin.clone(), self.direction.clone())
+    }
+}
+
+impl Line2 {
+    pub fn new(point: Point2, direction: Vector2) -> Self {
+        Line2 { point, direction }
+    }
+
+    pub fn through(pt_1: Point2, pt_2: &Point2) -> Self {
+        let direction = pt_2.from(&pt_1);
+        Line2 {
+            point: pt_1,
+            direction,
+        }
+    }
+
+    // 到直线的有向距离, 在方向的左侧为正
+    pub fn signed_distance(&self, pt: &Point2) -> f64 {
+        self.direction.exterior(&pt.from(&self.point)) / self.direction.l2_norm()
+    }
+}
+
+impl Linear for Segment2 {
+    fn origin(&self) -> &Point2 {
+        &self.start
+    }
+
+    fn point_at(&self, t: f64) -> Point2 {
+        if t == 1. {
+            return self.end.clone();
+        }
+        self.start.move_along(&(self.vector() * t))
+    }
+
+    fn direction(&self) -> Vector2 {
+        self.vector()
+    }
+
+    fn range(&self) -> (f64, f64) {
+        (0., 1.)
+    }
+}
+
+impl Linear for Ray2 {
+    fn origin(&self) -> &Point2 {
+        &self.origin
+    }
+
+    fn direction(&self) -> Vector2 {
+        self.direction.clone()
+    }
+
+    fn range(&self) -> (f64, f64) {
+        (0., f64::INFINITY)
+    }
+}
+
+impl Linear for Line2 {
+    fn origin(&self) -> &Point2 {
+        &self.point
+    }
+
+    fn direction(&self) -> Vector2 {
+        self.direction.clone()
+    }
+
+    fn range(&self) -> (f64, f64) {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    }
+}
+
+impl fmt::Display for Segment2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Segment: [{}, {}]", self.start, self.end)
+    }
+}
+
+impl fmt::Display for Ray2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ray: [{}, {}]", self.origin, self.direction)
+    }
+}
+
+impl fmt::Display for Line2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line: [{}, {}]", self.point, self.direction)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Intersection, Line2, Linear, Ray2, Segment2};
+    use crate::base::{Point2, Vector2};
+
+    fn seg(x0: f64, y0: f64, x1: f64, y1: f64) -> Segment2 {
+        Segment2::new(Point2::new(x0, y0), Point2::new(x1, y1))
+    }
+
+    #[test]
+    fn test_intersection() {
+        let s = seg(0., 0., 2., 2.);
+        assert_eq!(
+            s.intersection(&seg(0., 2., 2., 0.)),
+            Intersection::Point(Point2::new(1., 1.))
+        );
+        // 端点接触
+        assert_eq!(
+            s.intersection(&seg(2., 2., 3., 0.)),
+            Intersection::Point(Point2::new(2., 2.))
+        );
+        assert_eq!(s.intersection(&seg(0., 1., 1., 2.)), Intersection::None);
+        assert_eq!(s.intersection(&seg(3., 0., 3., 5.)), Intersection::None);
+        // 共线重叠, 方向相反
+        assert_eq!(
+            s.intersection(&seg(3., 3., 1., 1.)),
+            Intersection::Segment(seg(1., 1., 2., 2.))
+        );
+        assert_eq!(s.intersection(&seg(3., 3., 4., 4.)), Intersection::None);
+
+        let ray = Ray2::new(Point2::new(1., 0.), Vector2::new(1., 0.));
+        assert_eq!(
+            ray.intersection(&seg(0., 0., 3., 0.)),
+            Intersection::Segment(seg(1., 0., 3., 0.))
+        );
+        assert_eq!(
+            ray.intersection(&Ray2::new(Point2::new(5., 0.), Vector2::new(2., 0.))),
+            Intersection::Ray(Ray2::new(Point2::new(5., 0.), Vector2::new(1., 0.)))
+        );
+        // 相对的两条射线重叠为线段
+        assert_eq!(
+            ray.intersection(&Ray2::new(Point2::new(5., 0.), Vector2::new(-1., 0.))),
+            Intersection::Segment(seg(1., 0., 5., 0.))
+        );
+        assert_eq!(
+            ray.intersection(&Line2::new(Point2::new(0., 0.), Vector2::new(0., 1.))),
+            Intersection::None
+        );
+        let line = Line2::through(Point2::new(0., 1.), &Point2::new(1., 1.));
+        assert_eq!(
+            line.intersection(&Line2::new(Point2::new(3., 1.), Vector2::new(-2., 0.))),
+            Intersection::Line(line.clone())
+        );
+        assert_eq!(line.intersection(&ray.line()), Intersection::None);
+    }
+
+    #[test]
+    fn test_projection() {
+        let s = seg(0., 0., 4., 0.);
+        let pt = Point2::new(1., 3.);
+        assert_eq!(s.param_of(&pt), 0.25);
+        assert_eq!(s.project(&pt), Point2::new(1., 0.));
+        assert_eq!(s.distance_to(&Point2::new(6., 0.)), 2.);
+        assert_eq!(s.midpoint(), Point2::new(2., 0.));
+        assert_eq!(s.point_at(0.75), Point2::new(3., 0.));
+        let line = s.line();
+        assert_eq!(line.distance_to(&Point2::new(6., -1.)), 1.);
+        assert_eq!(line.signed_distance(&Point2::new(6., -1.)), -1.);
+        let ray = Ray2::through(Point2::new(0., 0.), &Point2::new(0., 2.));
+        assert_eq!(ray.project(&Point2::new(1., -1.)), Point2::new(0., 0.));
+        assert!(ray.contains(&Point2::new(0., 100.)));
+    }
+}
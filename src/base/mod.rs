@@ -1,8 +1,14 @@
+pub mod circle;
 pub mod color;
+pub mod line;
 pub mod point;
+pub mod transform;
 pub mod vector;
 pub use point::Point2;
 pub use vector::Vector2;
+pub use circle::Circle;
+pub use line::{Intersection, Line2, Linear, Ray2, Segment2};
+pub use transform::{Decomposition, Transform, Transform2};
 pub use color:: Color;
 
 pub const EPSILON: f64 = 1e-6;
@@ -0,0 +1,276 @@
+use super::{Point2, Vector2, EPSILON};
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+// 二维仿射变换, 齐次坐标下的 3x3 矩阵
+// | a c tx |
+// | b d ty |
+// | 0 0 1  |
+// 只存前两行, 与 canvas 的 setTransform(a, b, c, d, tx, ty) 一致
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transform2 {
+    m: [[f64; 3]; 2],
+}
+
+// 分解为 平移 * 旋转 * 错切 * 缩放, 错切只沿 x 方向
+// 行列式为负 (有镜像) 时 scale.y 为负
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decomposition {
+    pub translation: Vector2,
+    pub rotation: f64,
+    pub shear: f64,
+    pub scale: Vector2,
+}
+
+// 可以做仿射变换的对象
+pub trait Transform {
+    fn transform(&self, t: &Transform2) -> Self;
+
+    fn translate(&self, v: &Vector2) -> Self
+    where
+        Self: Sized,
+    {
+        self.transform(&Transform2::translation(v.x(), v.y()))
+    }
+
+    // 绕 center 逆时针旋转 angle 弧度
+    fn rotate_about(&self, center: &Point2, angle: f64) -> Self
+    where
+        Self: Sized,
+    {
+        self.transform(&Transform2::identity().rotate_about(center, angle))
+    }
+}
+
+impl Transform2 {
+    pub fn new(a: f64, b: f64, c: f64, d: f64, tx: f64, ty: f64) -> Self {
+        Transform2 {
+            m: [[a, c, tx], [b, d, ty]],
+        }
+    }
+
+    pub fn identity() -> Self {
+        Transform2::new(1., 0., 0., 1., 0., 0.)
+    }
+
+    pub fn translation(dx: f64, dy: f64) -> Self {
+        Transform2::new(1., 0., 0., 1., dx, dy)
+    }
+
+    // 逆时针旋转 angle 弧度
+    pub fn rotation(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Transform2::new(cos, sin, -sin, cos, 0., 0.)
+    }
+
+    pub fn scaling(sx: f64, sy: f64) -> Self {
+        Transform2::new(sx, 0., 0., sy, 0., 0.)
+    }
+
+    // (x, y) -> (x + kx * y, y + ky * x)
+    pub fn shearing(kx: f64, ky: f64) -> Self {
+        Transform2::new(1., ky, kx, 1., 0., 0.)
+    }
+
+    // 完整的 3x3 矩阵
+    pub fn matrix(&self) -> [[f64; 3]; 3] {
+        [self.m[0], self.m[1], [0., 0., 1.]]
+    }
+
+    // canvas setTransform 的参数顺序
+    pub fn to_canvas(&self) -> [f64; 6] {
+        let m = &self.m;
+        [m[0][0], m[1][0], m[0][1], m[1][1], m[0][2], m[1][2]]
+    }
+
+    // self * other, 即先做 other 再做 self
+    pub fn compose(&self, other: &Transform2) -> Transform2 {
+        let (a, b) = (&self.m, &other.m);
+        let mut m = [[0.; 3]; 2];
+        for (row, res) in m.iter_mut().enumerate() {
+            for (col, value) in res.iter_mut().enumerate() {
+                *value = a[row][0] * b[0][col] + a[row][1] * b[1][col];
+            }
+            res[2] += a[row][2];
+        }
+        Transform2 { m }
+    }
+
+    // 以下链式方法都是在当前变换之后再做一次变换
+    pub fn translate(&self, dx: f64, dy: f64) -> Transform2 {
+        Transform2::translation(dx, dy).compose(self)
+    }
+
+    pub fn rotate(&self, angle: f64) -> Transform2 {
+        Transform2::rotation(angle).compose(self)
+    }
+
+    pub fn rotate_about(&self, center: &Point2, angle: f64) -> Transform2 {
+        self.translate(-center.x(), -center.y())
+            .rotate(angle)
+            .translate(center.x(), center.y())
+    }
+
+    pub fn scale(&self, sx: f64, sy: f64) -> Transform2 {
+        Transform2::scaling(sx, sy).compose(self)
+    }
+
+    pub fn scale_about(&self, center: &Point2, sx: f64, sy: f64) -> Transform2 {
+        self.translate(-center.x(), -center.y())
+            .scale(sx, sy)
+            .translate(center.x(), center.y())
+    }
+
+    pub fn shear(&self, kx: f64, ky: f64) -> Transform2 {
+        Transform2::shearing(kx, ky).compose(self)
+    }
+
+    // 线性部分的行列式, 为负时变换带镜像, 会改变环的方向
+    pub fn determinant(&self) -> f64 {
+        self.m[0][0] * self.m[1][1] - self.m[0][1] * self.m[1][0]
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.m
+            .iter()
+            .flatten()
+            .zip(Transform2::identity().m.iter().flatten())
+            .all(|(x, y)| (x - y).abs() < EPSILON)
+    }
+
+    // 退化 (行列式为 0) 时没有逆
+    pub fn inverse(&self) -> Option<Transform2> {
+        let det = self.determinant();
+        if det.abs() < EPSILON * EPSILON || !det.is_finite() {
+            return None;
+        }
+        let [[a, c, tx], [b, d, ty]] = self.m;
+        Some(Transform2::new(
+            d / det,
+            -b / det,
+            -c / det,
+            a / det,
+            (c * ty - d * tx) / det,
+            (b * tx - a * ty) / det,
+        ))
+    }
+
+    // 退化时返回 None
+    pub fn decompose(&self) -> Option<Decomposition> {
+        let [[a, c, tx], [b, d, ty]] = self.m;
+        let sx = a.hypot(b);
+        if sx < EPSILON || self.determinant().abs() < EPSILON * EPSILON {
+            return None;
+        }
+        let rotation = b.atan2(a);
+        let (sin, cos) = rotation.sin_cos();
+        // 第二列旋转回去后是 (shear * sy, sy)
+        let sy = d * cos - c * sin;
+        let shear = (c * cos + d * sin) / sy;
+        Some(Decomposition {
+            translation: Vector2::new(tx, ty),
+            rotation,
+            shear,
+            scale: Vector2::new(sx, sy),
+        })
+    }
+
+    pub fn apply(&self, pt: &Point2) -> Point2 {
+        let m = &self.m;
+        Point2::new(
+            m[0][0] * pt.x() + m[0][1] * pt.y() + m[0][2],
+            m[1][0] * pt.x() + m[1][1] * pt.y() + m[1][2],
+        )
+    }
+
+    // 向量只受线性部分影响
+    pub fn apply_vector(&self, v: &Vector2) -> Vector2 {
+        let m = &self.m;
+        Vector2::new(
+            m[0][0] * v.x() + m[0][1] * v.y(),
+            m[1][0] * v.x() + m[1][1] * v.y(),
+        )
+    }
+}
+
+impl Default for Transform2 {
+    fn default() -> Self {
+        Transform2::identity()
+    }
+}
+
+impl From<&Decomposition> for Transform2 {
+    fn from(dec: &Decomposition) -> Self {
+        Transform2::scaling(dec.scale.x(), dec.scale.y())
+            .shear(dec.shear, 0.)
+            .rotate(dec.rotation)
+            .translate(dec.translation.x(), dec.translation.y())
+    }
+}
+
+impl Transform for Point2 {
+    fn transform(&self, t: &Transform2) -> Self {
+        t.apply(self)
+    }
+}
+
+impl Transform for Vector2 {
+    fn transform(&self, t: &Transform2) -> Self {
+        t.apply_vector(self)
+    }
+}
+
+impl fmt::Display for Transform2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [[a, c, tx], [b, d, ty]] = self.m;
+        write!(
+            f,
+            "Transform: [[{}, {}, {}], [{}, {}, {}]]",
+            a, c, tx, b, d, ty
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Transform, Transform2};
+    use crate::base::{AlmostEqual, Point2, Vector2};
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_transform() {
+        let pt = Point2::new(2., 1.);
+        let t = Transform2::identity().rotate(FRAC_PI_2).translate(1., 0.);
+        assert!(pt.transform(&t).almost_equal(&Point2::new(0., 2.)));
+        // 向量不受平移影响
+        assert!(Vector2::new(2., 1.)
+            .transform(&t)
+            .almost_equal(&Vector2::new(-1., 2.)));
+        assert!(pt
+            .rotate_about(&Point2::new(1., 1.), FRAC_PI_2)
+            .almost_equal(&Point2::new(1., 2.)));
+        // compose 先做右边的变换
+        let s = Transform2::scaling(2., 3.);
+        assert!(pt
+            .transform(&t.compose(&s))
+            .almost_equal(&pt.transform(&s).transform(&t)));
+
+        let t = Transform2::identity()
+            .scale(2., -0.5)
+            .shear(0.3, 0.)
+            .rotate(1.2)
+            .translate(4., -1.);
+        assert!(t.compose(&t.inverse().unwrap()).is_identity());
+        assert!(t.inverse().unwrap().compose(&t).is_identity());
+        assert_eq!(Transform2::scaling(1., 0.).inverse(), None);
+
+        let dec = t.decompose().unwrap();
+        assert!((dec.rotation - 1.2).abs() < 1e-9);
+        assert!((dec.shear - 0.3).abs() < 1e-9);
+        assert!(dec.scale.almost_equal(&Vector2::new(2., -0.5)));
+        assert!(Transform2::from(&dec)
+            .compose(&t.inverse().unwrap())
+            .is_identity());
+    }
+}
@@ -0,0 +1,523 @@
+use super::utils::{cross, in_box, orientation, oriented_rings, point_in_rings, signed_area};
+use super::{GeneralPolygon, SinglePolygon};
+use crate::base::EPSILON;
+use geo::{LineString, Point};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum BooleanOp {
+    Union,
+    Intersection,
+    Difference,
+    Xor,
+}
+
+impl BooleanOp {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            BooleanOp::Union => a || b,
+            BooleanOp::Intersection => a && b,
+            BooleanOp::Difference => a && !b,
+            BooleanOp::Xor => a != b,
+        }
+    }
+}
+
+pub trait BooleanOps<Rhs = Self> {
+    fn boolean_op(&self, other: &Rhs, op: BooleanOp) -> GeneralPolygon;
+
+    fn union(&self, other: &Rhs) -> GeneralPolygon {
+        self.boolean_op(other, BooleanOp::Union)
+    }
+
+    fn intersection(&self, other: &Rhs) -> GeneralPolygon {
+        self.boolean_op(other, BooleanOp::Intersection)
+    }
+
+    fn difference(&self, other: &Rhs) -> GeneralPolygon {
+        self.boolean_op(other, BooleanOp::Difference)
+    }
+
+    fn xor(&self, other: &Rhs) -> GeneralPolygon {
+        self.boolean_op(other, BooleanOp::Xor)
+    }
+}
+
+impl SinglePolygon {
+    pub(crate) fn oriented_rings(&self) -> Vec<Vec<Point<f64>>> {
+        oriented_rings(&self._base_polygon)
+    }
+}
+
+impl GeneralPolygon {
+    // 各多边形互不重叠
+    pub(crate) fn oriented_rings(&self) -> Vec<Vec<Point<f64>>> {
+        self.polygons
+            .iter()
+            .flat_map(|pol| pol.oriented_rings())
+            .collect()
+    }
+}
+
+impl BooleanOps for SinglePolygon {
+    fn boolean_op(&self, other: &SinglePolygon, op: BooleanOp) -> GeneralPolygon {
+        boolean(&self.oriented_rings(), &other.oriented_rings(), op)
+    }
+}
+
+impl BooleanOps<SinglePolygon> for GeneralPolygon {
+    fn boolean_op(&self, other: &SinglePolygon, op: BooleanOp) -> GeneralPolygon {
+        boolean(&self.oriented_rings(), &other.oriented_rings(), op)
+    }
+}
+
+impl BooleanOps for GeneralPolygon {
+    fn boolean_op(&self, other: &GeneralPolygon, op: BooleanOp) -> GeneralPolygon {
+        boolean(&self.oriented_rings(), &other.oriented_rings(), op)
+    }
+}
+
+type EdgeCounts = BTreeMap<(usize, usize), [u32; 4]>;
+
+struct Segment {
+    a: Point<f64>,
+    b: Point<f64>,
+    owner: usize,
+    splits: Vec<Point<f64>>,
+}
+
+impl Segment {
+    fn min_x(&self) -> f64 {
+        self.a.x().min(self.b.x())
+    }
+
+    fn max_x(&self) -> f64 {
+        self.a.x().max(self.b.x())
+    }
+}
+
+// 两个区域的布尔运算
+// 输入环外环逆时针, 内环顺时针, 区域在边的左侧
+// 1. 按 x 扫描, 在所有交点 (含共线重叠端点) 处切分边
+// 2. 顶点按 EPSILON 合并, 重合边合并为一条
+// 3. 每条边记录两侧分别是否属于两个区域, 按运算保留两侧结果不同的边
+// 4. 沿保留的有向边连成环, 再按有向面积分为外环和洞
+pub(crate) fn boolean(
+    subject: &[Vec<Point<f64>>],
+    clip: &[Vec<Point<f64>>],
+    op: BooleanOp,
+) -> GeneralPolygon {
+    let regions = [subject, clip];
+    let (pts, edges) = arrangement(&regions);
+    let mut directed: Vec<(usize, usize)> = vec![];
+    for (&(u, v), counts) in edges.iter() {
+        let mid = Point::new(
+            (pts[u].x() + pts[v].x()) / 2.,
+            (pts[u].y() + pts[v].y()) / 2.,
+        );
+        let mut left = [false; 2];
+        let mut right = [false; 2];
+        for owner in 0..2 {
+            let (fwd, bwd) = (counts[2 * owner], counts[2 * owner + 1]);
+            if fwd != bwd {
+                left[owner] = fwd > bwd;
+                right[owner] = bwd > fwd;
+            } else {
+                let inside = point_in_rings(regions[owner], &mid);
+                left[owner] = inside;
+                right[owner] = inside;
+            }
+        }
+        let in_left = op.apply(left[0], left[1]);
+        let in_right = op.apply(right[0], right[1]);
+        if in_left && !in_right {
+            directed.push((u, v));
+        } else if in_right && !in_left {
+            directed.push((v, u));
+        }
+    }
+    assemble(&pts, &directed)
+}
+
+// 可能自相交的环按正环绕数规则合并, 环绕数大于 0 的部分为结果
+pub(crate) fn positive_fill(rings: &[Vec<Point<f64>>]) -> GeneralPolygon {
+    let (pts, edges) = arrangement(&[rings]);
+    // 每条边的净重数, 方向为小顶点到大顶点
+    let weighted: Vec<((usize, usize), i64)> = edges
+        .iter()
+        .map(|(&key, counts)| (key, counts[0] as i64 - counts[1] as i64))
+        .filter(|&(_, w)| w != 0)
+        .collect();
+
+    let mut directed: Vec<(usize, usize)> = vec![];
+    for &((u, v), w) in weighted.iter() {
+        let (a, b) = (&pts[u], &pts[v]);
+        // 水平边旋转 90 度后计算, 使射线不与之重合
+        let horizontal = (a.y() - b.y()).abs() <= (a.x() - b.x()).abs();
+        let rotate = |pt: &Point<f64>| {
+            if horizontal {
+                (pt.y(), -pt.x())
+            } else {
+                (pt.x(), pt.y())
+            }
+        };
+        let (ra, rb) = (rotate(a), rotate(b));
+        let mid = ((ra.0 + rb.0) / 2., (ra.1 + rb.1) / 2.);
+
+        // 从中点向 +x 的射线, 得到边右侧 (东侧) 的环绕数
+        let mut east = 0;
+        for &((p, q), wq) in weighted.iter() {
+            if (p, q) == (u, v) {
+                continue;
+            }
+            let (rp, rq) = (rotate(&pts[p]), rotate(&pts[q]));
+            let side = (rq.0 - rp.0) * (mid.1 - rp.1) - (mid.0 - rp.0) * (rq.1 - rp.1);
+            if rp.1 <= mid.1 && rq.1 > mid.1 && side > 0. {
+                east += wq;
+            } else if rq.1 <= mid.1 && rp.1 > mid.1 && side < 0. {
+                east -= wq;
+            }
+        }
+        let upward = rb.1 > ra.1;
+        let west = east + if upward { w } else { -w };
+        let (left, right) = if upward { (west, east) } else { (east, west) };
+        if left > 0 && right <= 0 {
+            directed.push((u, v));
+        } else if right > 0 && left <= 0 {
+            directed.push((v, u));
+        }
+    }
+    assemble(&pts, &directed)
+}
+
+// 切分所有相交的边并合并顶点
+// 返回顶点和 (小顶点, 大顶点) -> 各区域中沿正向, 反向的边数
+fn arrangement(regions: &[&[Vec<Point<f64>>]]) -> (Vec<Point<f64>>, EdgeCounts) {
+    let mut segs: Vec<Segment> = vec![];
+    for (owner, rings) in regions.iter().enumerate() {
+        for ring in rings.iter() {
+            let n = ring.len();
+            for i in 0..n {
+                let (a, b) = (ring[i], ring[(i + 1) % n]);
+                if a != b {
+                    segs.push(Segment {
+                        a,
+                        b,
+                        owner,
+                        splits: vec![],
+                    });
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..segs.len()).collect();
+    order.sort_by(|&i, &j| {
+        segs[i]
+            .min_x()
+            .partial_cmp(&segs[j].min_x())
+            .unwrap_or(Ordering::Equal)
+    });
+    let mut active: Vec<usize> = vec![];
+    for &i in order.iter() {
+        let x = segs[i].min_x();
+        active.retain(|&j| segs[j].max_x() >= x - EPSILON);
+        for &j in active.iter() {
+            let (on_i, on_j) = split_points(&segs[i], &segs[j]);
+            segs[i].splits.extend(on_i);
+            segs[j].splits.extend(on_j);
+        }
+        active.push(i);
+    }
+
+    let mut snapper = Snapper::default();
+    let mut edges: EdgeCounts = BTreeMap::new();
+    for seg in segs.iter() {
+        let dir = (seg.b.x() - seg.a.x(), seg.b.y() - seg.a.y());
+        let len = dir.0 * dir.0 + dir.1 * dir.1;
+        let mut pts: Vec<(f64, Point<f64>)> = seg
+            .splits
+            .iter()
+            .chain([seg.a, seg.b].iter())
+            .map(|pt| {
+                let t = ((pt.x() - seg.a.x()) * dir.0 + (pt.y() - seg.a.y()) * dir.1) / len;
+                (t, *pt)
+            })
+            .collect();
+        pts.sort_by(|p, q| p.0.partial_cmp(&q.0).unwrap_or(Ordering::Equal));
+        let mut ids: Vec<usize> = pts.iter().map(|(_, pt)| snapper.id(pt)).collect();
+        ids.dedup();
+        for w in ids.windows(2) {
+            let (u, v) = (w[0], w[1]);
+            let counts = edges.entry((u.min(v), u.max(v))).or_insert([0; 4]);
+            counts[2 * seg.owner + if u < v { 0 } else { 1 }] += 1;
+        }
+    }
+    (snapper.pts, edges)
+}
+
+// 沿有向边 (区域在左侧) 连成环, 再按有向面积分为外环和洞
+fn assemble(pts: &[Point<f64>], directed: &[(usize, usize)]) -> GeneralPolygon {
+    let mut shells: Vec<(f64, Vec<Point<f64>>)> = vec![];
+    let mut holes: Vec<Vec<Point<f64>>> = vec![];
+    for ids in trace_rings(pts, directed) {
+        for ids in split_loops(ids) {
+            let ring: Vec<Point<f64>> = ids.iter().map(|&id| pts[id]).collect();
+            let area = signed_area(&ring);
+            if area > EPSILON {
+                shells.push((area, ring));
+            } else if area < -EPSILON {
+                holes.push(ring);
+            }
+        }
+    }
+
+    let mut inners: Vec<Vec<LineString<f64>>> = vec![vec![]; shells.len()];
+    for hole in holes.into_iter() {
+        let rep = Point::new(
+            (hole[0].x() + hole[1].x()) / 2.,
+            (hole[0].y() + hole[1].y()) / 2.,
+        );
+        let owner = shells
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, shell))| point_in_rings(std::slice::from_ref(shell), &rep))
+            .min_by(|(_, (a, _)), (_, (b, _))| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .map(|(i, _)| i);
+        if let Some(i) = owner {
+            inners[i].push(LineString::from(hole));
+        }
+    }
+
+    shells
+        .into_iter()
+        .zip(inners)
+        .map(|((_, shell), inner)| SinglePolygon::new(LineString::from(shell), inner))
+        .collect()
+}
+
+// 线段 s, t 的交点, 分别返回落在 s 和 t 上需要切分的点
+fn split_points(s: &Segment, t: &Segment) -> (Vec<Point<f64>>, Vec<Point<f64>>) {
+    let (p1, p2, q1, q2) = (&s.a, &s.b, &t.a, &t.b);
+    let mut on_s = vec![];
+    let mut on_t = vec![];
+    if p1.y().max(p2.y()) < q1.y().min(q2.y()) - EPSILON
+        || q1.y().max(q2.y()) < p1.y().min(p2.y()) - EPSILON
+    {
+        return (on_s, on_t);
+    }
+    let d1 = orientation(q1, q2, p1);
+    let d2 = orientation(q1, q2, p2);
+    let d3 = orientation(p1, p2, q1);
+    let d4 = orientation(p1, p2, q2);
+    if (d1 == 0 && d2 == 0) || (d3 == 0 && d4 == 0) {
+        // 共线
+        for q in [q1, q2].iter() {
+            if in_box(p1, p2, q) {
+                on_s.push(**q);
+            }
+        }
+        for p in [p1, p2].iter() {
+            if in_box(q1, q2, p) {
+                on_t.push(**p);
+            }
+        }
+    } else if d1 * d2 < 0 && d3 * d4 < 0 {
+        let k = cross(q1, p1, q2) / (cross(q1, p1, q2) - cross(q1, p2, q2));
+        let pt = Point::new(
+            p1.x() + k * (p2.x() - p1.x()),
+            p1.y() + k * (p2.y() - p1.y()),
+        );
+        on_s.push(pt);
+        on_t.push(pt);
+    } else {
+        if d1 == 0 && in_box(q1, q2, p1) {
+            on_t.push(*p1);
+        }
+        if d2 == 0 && in_box(q1, q2, p2) {
+            on_t.push(*p2);
+        }
+        if d3 == 0 && in_box(p1, p2, q1) {
+            on_s.push(*q1);
+        }
+        if d4 == 0 && in_box(p1, p2, q2) {
+            on_s.push(*q2);
+        }
+    }
+    (on_s, on_t)
+}
+
+// 距离小于 EPSILON 的点视为同一顶点
+#[derive(Default)]
+struct Snapper {
+    pts: Vec<Point<f64>>,
+    grid: HashMap<(i64, i64), Vec<usize>>,
+}
+
+impl Snapper {
+    fn id(&mut self, pt: &Point<f64>) -> usize {
+        let cell = (
+            (pt.x() / EPSILON).floor() as i64,
+            (pt.y() / EPSILON).floor() as i64,
+        );
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                if let Some(ids) = self.grid.get(&(cell.0 + dx, cell.1 + dy)) {
+                    for &id in ids.iter() {
+                        let other = &self.pts[id];
+                        if (other.x() - pt.x()).abs() + (other.y() - pt.y()).abs() <= EPSILON {
+                            return id;
+                        }
+                    }
+                }
+            }
+        }
+        let id = self.pts.len();
+        self.pts.push(*pt);
+        self.grid.entry(cell).or_default().push(id);
+        id
+    }
+}
+
+// 沿有向边连成闭合环, 在公共顶点处选择顺时针方向上离来边最近的出边,
+// 使左侧的面保持最小
+fn trace_rings(pts: &[Point<f64>], edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let mut outgoing: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (i, &(u, _)) in edges.iter().enumerate() {
+        outgoing.entry(u).or_default().push(i);
+    }
+    let mut used = vec![false; edges.len()];
+    let mut rings = vec![];
+    for start in 0..edges.len() {
+        if used[start] {
+            continue;
+        }
+        let origin = edges[start].0;
+        let mut ring = vec![];
+        let mut cur = start;
+        let closed = loop {
+            used[cur] = true;
+            let (u, v) = edges[cur];
+            ring.push(u);
+            if v == origin {
+                break true;
+            }
+            let back = (pts[u].x() - pts[v].x(), pts[u].y() - pts[v].y());
+            let next = outgoing.get(&v).and_then(|candidates| {
+                candidates
+                    .iter()
+                    .filter(|&&i| !used[i])
+                    .map(|&i| {
+                        let w = edges[i].1;
+                        let dir = (pts[w].x() - pts[v].x(), pts[w].y() - pts[v].y());
+                        let ccw = (back.0 * dir.1 - back.1 * dir.0)
+                            .atan2(back.0 * dir.0 + back.1 * dir.1);
+                        let cw = if ccw >= 0. {
+                            2. * std::f64::consts::PI - ccw
+                        } else {
+                            -ccw
+                        };
+                        (cw, i)
+                    })
+                    .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal))
+                    .map(|(_, i)| i)
+            });
+            match next {
+                Some(i) => cur = i,
+                None => break false,
+            }
+        };
+        if closed && ring.len() >= 3 {
+            rings.push(ring);
+        }
+    }
+    rings
+}
+
+// 在重复顶点处把环拆成多个简单环
+fn split_loops(ring: Vec<usize>) -> Vec<Vec<usize>> {
+    let mut res = vec![];
+    let mut stack: Vec<usize> = vec![];
+    let mut pos: HashMap<usize, usize> = HashMap::new();
+    for id in ring.into_iter() {
+        if let Some(&i) = pos.get(&id) {
+            let part = stack.split_off(i);
+            for other in part.iter() {
+                pos.remove(other);
+            }
+            if part.len() >= 3 {
+                res.push(part);
+            }
+        }
+        pos.insert(id, stack.len());
+        stack.push(id);
+    }
+    if stack.len() >= 3 {
+        res.push(stack);
+    }
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::BooleanOps;
+    use crate::geometric::{FromPoints, SinglePolygon};
+
+    fn square(x: f64, y: f64, size: f64) -> SinglePolygon {
+        SinglePolygon::from_points(
+            vec![(x, y), (x + size, y), (x + size, y + size), (x, y + size)],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_overlapping_squares() {
+        let (a, b) = (square(0., 0., 2.), square(1., 1., 2.));
+        assert_eq!(a.union(&b).len(), 1);
+        assert!((a.union(&b).area() - 7.).abs() < 1e-9);
+        assert!((a.intersection(&b).area() - 1.).abs() < 1e-9);
+        assert!((a.difference(&b).area() - 3.).abs() < 1e-9);
+        let xor = a.xor(&b);
+        assert_eq!(xor.len(), 2);
+        assert!((xor.area() - 6.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_shared_edges_and_vertices() {
+        let (a, b) = (square(0., 0., 1.), square(1., 0., 1.));
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert!((union.area() - 2.).abs() < 1e-9);
+        assert!(a.intersection(&b).is_empty());
+
+        // 只有一个公共顶点
+        let c = square(1., 1., 1.);
+        let union = a.union(&c);
+        assert_eq!(union.len(), 2);
+        assert!((union.area() - 2.).abs() < 1e-9);
+
+        // 相同多边形
+        assert!((a.union(&a).area() - 1.).abs() < 1e-9);
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn test_holes() {
+        let (outer, inner) = (square(0., 0., 4.), square(1., 1., 2.));
+        let ring = outer.difference(&inner);
+        assert_eq!(ring.len(), 1);
+        assert_eq!(ring.num_hole(), 1);
+        assert!((ring.area() - 12.).abs() < 1e-9);
+
+        // 填上洞的一部分
+        let union = ring.get(0).unwrap().union(&square(1., 1., 1.));
+        assert_eq!(union.num_hole(), 1);
+        assert!((union.area() - 13.).abs() < 1e-9);
+
+        // 洞被完全覆盖
+        let union = ring.get(0).unwrap().union(&square(0.5, 0.5, 3.));
+        assert_eq!(union.num_hole(), 0);
+        assert!((union.area() - 16.).abs() < 1e-9);
+    }
+}
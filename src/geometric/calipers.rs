@@ -0,0 +1,303 @@
+use super::minkowski::{ccw_ring, lowest, reflect};
+use super::ConvexPolygon;
+use crate::base::point::is_ccw;
+use crate::base::{Point2, Vector2, EPSILON};
+use geo::{LineString, Point};
+use std::cmp::Ordering;
+
+// 有向矩形, 逆时针的四个角依次为 origin, origin + u, origin + u + v, origin + v
+#[derive(PartialEq, Clone, Debug)]
+pub struct OrientedRect {
+    pub origin: Point2,
+    pub u: Vector2,
+    pub v: Vector2,
+}
+
+impl OrientedRect {
+    pub fn corners(&self) -> [Point2; 4] {
+        let far = self.origin.move_along(&self.u);
+        [
+            self.origin.clone(),
+            far.clone(),
+            far.move_along(&self.v),
+            self.origin.move_along(&self.v),
+        ]
+    }
+
+    pub fn area(&self) -> f64 {
+        self.u.l2_norm() * self.v.l2_norm()
+    }
+
+    pub fn perimeter(&self) -> f64 {
+        2. * (self.u.l2_norm() + self.v.l2_norm())
+    }
+}
+
+impl From<OrientedRect> for ConvexPolygon {
+    fn from(rect: OrientedRect) -> Self {
+        let pts: Vec<Point<f64>> = rect
+            .corners()
+            .iter()
+            .map(|pt| Point::new(pt.x(), pt.y()))
+            .collect();
+        ConvexPolygon::new(LineString::from(pts))
+    }
+}
+
+// 旋转卡壳, 均为 O(n)
+// 去重后顶点少于 2 个时返回 None
+impl ConvexPolygon {
+    // 距离最远的两个顶点
+    pub fn diameter(&self) -> Option<(Point2, Point2)> {
+        let pts = strict_ring(self);
+        let n = pts.len();
+        if n < 2 {
+            return None;
+        }
+        let mut best = (0, 0);
+        let mut update = |i: usize, j: usize| {
+            if pts[i].square_euclid_distance(&pts[j])
+                > pts[best.0].square_euclid_distance(&pts[best.1])
+            {
+                best = (i, j);
+            }
+        };
+        for (i, j) in antipodal(&pts) {
+            update(i, j);
+            update((i + 1) % n, j);
+        }
+        Some((pts[best.0].clone(), pts[best.1].clone()))
+    }
+
+    // 最小宽度, 以及测量宽度的单位方向 (最窄时某条边的内法向量)
+    pub fn min_width(&self) -> Option<(f64, Vector2)> {
+        let pts = strict_ring(self);
+        if pts.len() < 2 {
+            return None;
+        }
+        let n = pts.len();
+        antipodal(&pts)
+            .into_iter()
+            .map(|(i, j)| {
+                let edge = pts[(i + 1) % n].from(&pts[i]);
+                let width = edge.exterior(&pts[j].from(&pts[i])) / edge.l2_norm();
+                (width, edge.normal_vector())
+            })
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal))
+    }
+
+    // 面积最小的外接矩形, 必有一条边与多边形的某条边共线
+    pub fn min_area_rect(&self) -> Option<OrientedRect> {
+        min_rect_by(self, OrientedRect::area)
+    }
+
+    // 周长最小的外接矩形
+    pub fn min_perimeter_rect(&self) -> Option<OrientedRect> {
+        min_rect_by(self, OrientedRect::perimeter)
+    }
+
+    // 两个凸多边形之间距离最远的点对, 分别在 self 和 other 上
+    // 即 self ⊕ (-other) 上离原点最远的顶点, 按边的极角归并, O(n + m)
+    pub fn max_distance(&self, other: &ConvexPolygon) -> Option<(Point2, Point2)> {
+        let a = strict_ring(self);
+        let b = reflect(&strict_ring(other));
+        let (n, m) = (a.len(), b.len());
+        if n == 0 || m == 0 {
+            return None;
+        }
+        let (i0, j0) = (lowest(&a), lowest(&b));
+        let pa = |i: usize| &a[(i0 + i) % n];
+        let pb = |j: usize| &b[(j0 + j) % m];
+
+        let mut best = (0, 0, f64::NEG_INFINITY);
+        let (mut i, mut j) = (0, 0);
+        while i < n || j < m {
+            let dist = pa(i).square_euclid_distance(&Point2::new(-pb(j).x(), -pb(j).y()));
+            if dist > best.2 {
+                best = (i, j, dist);
+            }
+            let c = pa(i + 1).from(pa(i)).exterior(&pb(j + 1).from(pb(j)));
+            if j == m || (i < n && c > EPSILON) {
+                i += 1;
+            } else if i == n || c < -EPSILON {
+                j += 1;
+            } else {
+                i += 1;
+                j += 1;
+            }
+        }
+        let q = pb(best.1);
+        Some((pa(best.0).clone(), Point2::new(-q.x(), -q.y())))
+    }
+}
+
+// 逆时针且去掉共线点的顶点; 所有点共线时只保留两端
+pub(crate) fn strict_ring(pol: &ConvexPolygon) -> Vec<Point2> {
+    let ring = ccw_ring(&pol._base_polygon);
+    let mut res = ring.clone();
+    let mut k = 0;
+    while res.len() >= 3 && k < res.len() {
+        let len = res.len();
+        if is_ccw(&res[(k + len - 1) % len], &res[k], &res[(k + 1) % len]) {
+            k += 1;
+        } else {
+            res.remove(k);
+            k = k.saturating_sub(1);
+        }
+    }
+    if res.len() >= 3 || ring.len() < 2 {
+        return res;
+    }
+    let cmp = |a: &&Point2, b: &&Point2| a.partial_cmp(b).unwrap_or(Ordering::Equal);
+    let (lo, hi) = (
+        ring.iter().min_by(cmp).unwrap(),
+        ring.iter().max_by(cmp).unwrap(),
+    );
+    if lo == hi {
+        vec![lo.clone()]
+    } else {
+        vec![lo.clone(), hi.clone()]
+    }
+}
+
+// 每条边 i -> i + 1 与离它最远的顶点 j
+fn antipodal(pts: &[Point2]) -> Vec<(usize, usize)> {
+    let n = pts.len();
+    let height = |i: usize, j: usize| {
+        pts[(i + 1) % n]
+            .from(&pts[i])
+            .exterior(&pts[j].from(&pts[i]))
+    };
+    let mut res = Vec::with_capacity(n);
+    let mut j = 1 % n;
+    for i in 0..n {
+        while height(i, (j + 1) % n) > height(i, j) {
+            j = (j + 1) % n;
+        }
+        res.push((i, j));
+    }
+    res
+}
+
+// 以每条边为底边的外接矩形, 右, 上, 左三个卡壳只向前移动
+fn min_rect_by<F>(pol: &ConvexPolygon, key: F) -> Option<OrientedRect>
+where
+    F: Fn(&OrientedRect) -> f64,
+{
+    let pts = strict_ring(pol);
+    let n = pts.len();
+    if n < 2 {
+        return None;
+    }
+    let step = |k: usize, dir: &Vector2| pts[(k + 1) % n].from(&pts[k]).dot(dir);
+    let (mut right, mut top, mut left) = (0, 0, 0);
+    let mut best: (f64, Option<OrientedRect>) = (f64::INFINITY, None);
+    for i in 0..n {
+        let u = pts[(i + 1) % n].from(&pts[i]).normalize();
+        let nrm = u.normal_vector();
+        if i == 0 {
+            right = 1 % n;
+        }
+        while step(right, &u) > 0. {
+            right = (right + 1) % n;
+        }
+        if i == 0 {
+            top = right;
+        }
+        while step(top, &nrm) > 0. {
+            top = (top + 1) % n;
+        }
+        if i == 0 {
+            left = top;
+        }
+        while step(left, &u) < 0. {
+            left = (left + 1) % n;
+        }
+
+        let lo = pts[left].from(&pts[i]).dot(&u);
+        let hi = pts[right].from(&pts[i]).dot(&u);
+        let h = pts[top].from(&pts[i]).dot(&nrm);
+        let rect = OrientedRect {
+            origin: pts[i].move_along(&(u.clone() * lo)),
+            u: u.clone() * (hi - lo),
+            v: nrm * h,
+        };
+        let value = key(&rect);
+        if value < best.0 {
+            best = (value, Some(rect));
+        }
+    }
+    best.1
+}
+
+#[cfg(test)]
+mod test {
+    use crate::base::Point2;
+    use crate::geometric::{ConvexPolygon, HullOptions, PointsConvexHull};
+    use geo::LineString;
+
+    #[test]
+    fn test_calipers() {
+        // 旋转 45 度的 2 x 1 矩形, 顺时针存储
+        let s = std::f64::consts::FRAC_1_SQRT_2;
+        let rect = ConvexPolygon::new(LineString::from(vec![
+            (0.0, 0.0),
+            (-s, s),
+            (s, 3. * s),
+            (2. * s, 2. * s),
+        ]));
+        let (a, b) = rect.diameter().unwrap();
+        assert!((a.euclid_distance(&b) - 5f64.sqrt()).abs() < 1e-9);
+        let (width, dir) = rect.min_width().unwrap();
+        assert!((width - 1.).abs() < 1e-9);
+        assert!((dir.x().abs() - s).abs() < 1e-9);
+        let res = rect.min_area_rect().unwrap();
+        assert!((res.area() - 2.).abs() < 1e-9);
+        for corner in res.corners().iter() {
+            assert!(rect
+                .vertices()
+                .iter()
+                .any(|pt| { (pt.x() - corner.x()).abs() + (pt.y() - corner.y()).abs() < 1e-9 }));
+        }
+        assert!((rect.min_perimeter_rect().unwrap().perimeter() - 6.).abs() < 1e-9);
+
+        let other = ConvexPolygon::new(LineString::from(vec![(5.0, 0.0), (6.0, 0.0), (6.0, 1.0)]));
+        let (p, q) = rect.max_distance(&other).unwrap();
+        assert_eq!(p, Point2::new(-s, s));
+        assert_eq!(q, Point2::new(6.0, 0.0));
+    }
+
+    #[test]
+    fn test_calipers_brute_force() {
+        let mut seed: u64 = 7;
+        let mut rand = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 33) as f64 / (1u64 << 31) as f64 * 10.
+        };
+        for _ in 0..50 {
+            let pts: Vec<Point2> = (0..12).map(|_| Point2::new(rand(), rand())).collect();
+            let others: Vec<Point2> = (0..8).map(|_| Point2::new(rand() + 5., rand())).collect();
+            let pol = pts.convex_hull_with(&HullOptions::default()).polygon;
+            let other = others.convex_hull().polygon;
+
+            let brute = pts
+                .iter()
+                .flat_map(|a| pts.iter().map(move |b| a.euclid_distance(b)))
+                .fold(0., f64::max);
+            let (a, b) = pol.diameter().unwrap();
+            assert!((a.euclid_distance(&b) - brute).abs() < 1e-9);
+
+            let brute = pts
+                .iter()
+                .flat_map(|a| others.iter().map(move |b| a.euclid_distance(b)))
+                .fold(0., f64::max);
+            let (a, b) = pol.max_distance(&other).unwrap();
+            assert!((a.euclid_distance(&b) - brute).abs() < 1e-9);
+
+            let rect = pol.min_area_rect().unwrap();
+            let (width, _) = pol.min_width().unwrap();
+            assert!(rect.area() <= pol.min_perimeter_rect().unwrap().area() + 1e-9);
+            assert!(rect.u.l2_norm().min(rect.v.l2_norm()) >= width - 1e-9);
+        }
+    }
+}
@@ -0,0 +1,467 @@
+use super::boolean::{boolean, positive_fill, BooleanOp};
+use super::utils::{cross, oriented_rings, point_in_rings};
+use super::{ConvexPolygon, GeneralPolygon, SimplePolygon, SinglePolygon};
+use crate::base::{Point2, EPSILON};
+use geo::{LineString, Point, Polygon};
+use std::cmp::Ordering;
+
+// 轴对齐矩形, 如画布视口或瓦片
+#[derive(PartialEq, Clone, Debug)]
+pub struct Rect {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl Rect {
+    // 任意两个对角
+    pub fn new(pt_1: Point2, pt_2: Point2) -> Self {
+        Rect {
+            min: Point2::new(pt_1.x().min(pt_2.x()), pt_1.y().min(pt_2.y())),
+            max: Point2::new(pt_1.x().max(pt_2.x()), pt_1.y().max(pt_2.y())),
+        }
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max.x() - self.min.x()
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max.y() - self.min.y()
+    }
+
+    // 含边界
+    pub fn contains(&self, pt: &Point2) -> bool {
+        pt.x() >= self.min.x()
+            && pt.x() <= self.max.x()
+            && pt.y() >= self.min.y()
+            && pt.y() <= self.max.y()
+    }
+}
+
+impl From<Rect> for ConvexPolygon {
+    fn from(rect: Rect) -> Self {
+        ConvexPolygon::new(LineString::from(vec![
+            (rect.min.x(), rect.min.y()),
+            (rect.max.x(), rect.min.y()),
+            (rect.max.x(), rect.max.y()),
+            (rect.min.x(), rect.max.y()),
+        ]))
+    }
+}
+
+pub trait Clip {
+    // Sutherland–Hodgman 依次用矩形的四条边裁剪每个环
+    // 凹多边形被切成几块时, 结果中沿矩形边界往返的退化边相互抵消, 得到分开的多块
+    fn clip_to_rect(&self, rect: &Rect) -> GeneralPolygon;
+
+    // Weiler–Atherton: 在交点处交替沿两个多边形的边界走, 裁剪多边形可以是凹的, 可以有洞
+    // 交点落在顶点上或边共线重叠时改用通用的布尔运算
+    fn clip_by(&self, clip: &SinglePolygon) -> GeneralPolygon;
+
+    // 按边长为 size 的瓦片切分, 瓦片 (i, j) 为 [i * size, (i + 1) * size] x [j * size, (j + 1) * size]
+    // 只返回非空的瓦片
+    fn clip_to_tiles(&self, size: f64) -> Vec<((i64, i64), GeneralPolygon)>;
+}
+
+impl Clip for SinglePolygon {
+    fn clip_to_rect(&self, rect: &Rect) -> GeneralPolygon {
+        clip_to_rect(&self._base_polygon, rect)
+    }
+
+    fn clip_by(&self, clip: &SinglePolygon) -> GeneralPolygon {
+        weiler_atherton(
+            &oriented_rings(&self._base_polygon),
+            &oriented_rings(&clip._base_polygon),
+        )
+    }
+
+    fn clip_to_tiles(&self, size: f64) -> Vec<((i64, i64), GeneralPolygon)> {
+        clip_to_tiles(&self._base_polygon, size)
+    }
+}
+
+impl Clip for SimplePolygon {
+    fn clip_to_rect(&self, rect: &Rect) -> GeneralPolygon {
+        clip_to_rect(&self._base_polygon, rect)
+    }
+
+    fn clip_by(&self, clip: &SinglePolygon) -> GeneralPolygon {
+        weiler_atherton(
+            &oriented_rings(&self._base_polygon),
+            &oriented_rings(&clip._base_polygon),
+        )
+    }
+
+    fn clip_to_tiles(&self, size: f64) -> Vec<((i64, i64), GeneralPolygon)> {
+        clip_to_tiles(&self._base_polygon, size)
+    }
+}
+
+// 用半平面 keep(pt) 裁剪一个环, at(a, b) 为边 a -> b 与边界的交点
+fn clip_ring<K, A>(ring: &[Point<f64>], keep: K, at: A) -> Vec<Point<f64>>
+where
+    K: Fn(&Point<f64>) -> bool,
+    A: Fn(&Point<f64>, &Point<f64>) -> Point<f64>,
+{
+    let n = ring.len();
+    let mut res = vec![];
+    for i in 0..n {
+        let (a, b) = (&ring[i], &ring[(i + 1) % n]);
+        match (keep(a), keep(b)) {
+            (true, true) => res.push(*b),
+            (true, false) => res.push(at(a, b)),
+            (false, true) => {
+                res.push(at(a, b));
+                res.push(*b);
+            }
+            (false, false) => {}
+        }
+    }
+    res
+}
+
+fn clip_to_rect(pol: &Polygon<f64>, rect: &Rect) -> GeneralPolygon {
+    let rings = oriented_rings(pol);
+    let inside = |pt: &Point<f64>| rect.contains(&Point2::new(pt.x(), pt.y()));
+    if rings.iter().all(|ring| ring.iter().all(inside)) {
+        return GeneralPolygon::from(SinglePolygon::from(pol.clone()));
+    }
+    let (x0, y0, x1, y1) = (rect.min.x(), rect.min.y(), rect.max.x(), rect.max.y());
+    let at_x = |x: f64| {
+        move |a: &Point<f64>, b: &Point<f64>| {
+            let t = (x - a.x()) / (b.x() - a.x());
+            Point::new(x, a.y() + (b.y() - a.y()) * t)
+        }
+    };
+    let at_y = |y: f64| {
+        move |a: &Point<f64>, b: &Point<f64>| {
+            let t = (y - a.y()) / (b.y() - a.y());
+            Point::new(a.x() + (b.x() - a.x()) * t, y)
+        }
+    };
+    let clipped: Vec<Vec<Point<f64>>> = rings
+        .iter()
+        .map(|ring| {
+            let ring = clip_ring(ring, |pt| pt.x() >= x0, at_x(x0));
+            let ring = clip_ring(&ring, |pt| pt.x() <= x1, at_x(x1));
+            let ring = clip_ring(&ring, |pt| pt.y() >= y0, at_y(y0));
+            clip_ring(&ring, |pt| pt.y() <= y1, at_y(y1))
+        })
+        .filter(|ring| ring.len() >= 3)
+        .collect();
+    positive_fill(&clipped)
+}
+
+fn clip_to_tiles(pol: &Polygon<f64>, size: f64) -> Vec<((i64, i64), GeneralPolygon)> {
+    let pts = pol.exterior();
+    if size <= 0. || pts.0.is_empty() {
+        return vec![];
+    }
+    let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+    let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for pt in pts.points_iter() {
+        min_x = min_x.min(pt.x());
+        min_y = min_y.min(pt.y());
+        max_x = max_x.max(pt.x());
+        max_y = max_y.max(pt.y());
+    }
+    let (i0, j0) = ((min_x / size).floor() as i64, (min_y / size).floor() as i64);
+    let (i1, j1) = ((max_x / size).ceil() as i64, (max_y / size).ceil() as i64);
+    let mut res = vec![];
+    for i in i0..i1.max(i0 + 1) {
+        for j in j0..j1.max(j0 + 1) {
+            let rect = Rect::new(
+                Point2::new(i as f64 * size, j as f64 * size),
+                Point2::new((i + 1) as f64 * size, (j + 1) as f64 * size),
+            );
+            let piece = clip_to_rect(pol, &rect);
+            if !piece.is_empty() {
+                res.push(((i, j), piece));
+            }
+        }
+    }
+    res
+}
+
+// 主体边与裁剪边的交点, entering 表示主体沿该边进入裁剪区域
+struct Crossing {
+    pt: Point<f64>,
+    entering: bool,
+    // 在主体和裁剪多边形的展开序列中的位置 (环, 下标)
+    subject: (usize, usize),
+    clip: (usize, usize),
+}
+
+enum Node {
+    Vertex(Point<f64>),
+    Crossing(usize),
+}
+
+// 每个环展开为顶点和交点的序列, 交点按在边上的参数排序
+fn expand(rings: &[Vec<Point<f64>>], on_edge: &[Vec<Vec<(f64, usize)>>]) -> Vec<Vec<Node>> {
+    rings
+        .iter()
+        .zip(on_edge.iter())
+        .map(|(ring, edges)| {
+            let mut seq = vec![];
+            for (pt, crossings) in ring.iter().zip(edges.iter()) {
+                seq.push(Node::Vertex(*pt));
+                let mut crossings = crossings.clone();
+                crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+                seq.extend(crossings.into_iter().map(|(_, id)| Node::Crossing(id)));
+            }
+            seq
+        })
+        .collect()
+}
+
+fn weiler_atherton(subject: &[Vec<Point<f64>>], clip: &[Vec<Point<f64>>]) -> GeneralPolygon {
+    match trace(subject, clip) {
+        Some(rings) => positive_fill(&rings),
+        None => boolean(subject, clip, BooleanOp::Intersection),
+    }
+}
+
+// 交点退化时返回 None
+fn trace(subject: &[Vec<Point<f64>>], clip: &[Vec<Point<f64>>]) -> Option<Vec<Vec<Point<f64>>>> {
+    let mut crossings: Vec<Crossing> = vec![];
+    let mut subject_edges: Vec<Vec<Vec<(f64, usize)>>> = subject
+        .iter()
+        .map(|ring| vec![vec![]; ring.len()])
+        .collect();
+    let mut clip_edges: Vec<Vec<Vec<(f64, usize)>>> =
+        clip.iter().map(|ring| vec![vec![]; ring.len()]).collect();
+
+    for (si, ring) in subject.iter().enumerate() {
+        let n = ring.len();
+        for i in 0..n {
+            let (a, b) = (&ring[i], &ring[(i + 1) % n]);
+            let r = (b.x() - a.x(), b.y() - a.y());
+            for (ci, other) in clip.iter().enumerate() {
+                let m = other.len();
+                for j in 0..m {
+                    let (c, d) = (&other[j], &other[(j + 1) % m]);
+                    let s = (d.x() - c.x(), d.y() - c.y());
+                    let ac = (c.x() - a.x(), c.y() - a.y());
+                    let denom = r.0 * s.1 - r.1 * s.0;
+                    let scale = (r.0.hypot(r.1) * s.0.hypot(s.1)).max(EPSILON);
+                    if denom.abs() <= EPSILON * scale {
+                        // 平行; 共线且有重叠时退化
+                        let overlap = [c, d].iter().any(|pt| {
+                            let t = ((pt.x() - a.x()) * r.0 + (pt.y() - a.y()) * r.1)
+                                / (r.0 * r.0 + r.1 * r.1);
+                            t > -EPSILON && t < 1. + EPSILON
+                        }) || [a, b].iter().any(|pt| {
+                            let u = ((pt.x() - c.x()) * s.0 + (pt.y() - c.y()) * s.1)
+                                / (s.0 * s.0 + s.1 * s.1);
+                            u > -EPSILON && u < 1. + EPSILON
+                        });
+                        if cross(a, b, c).abs() <= EPSILON * r.0.hypot(r.1) && overlap {
+                            return None;
+                        }
+                        continue;
+                    }
+                    let t = (ac.0 * s.1 - ac.1 * s.0) / denom;
+                    let u = (ac.0 * r.1 - ac.1 * r.0) / denom;
+                    let on_segment = -EPSILON..=1. + EPSILON;
+                    if !on_segment.contains(&t) || !on_segment.contains(&u) {
+                        continue;
+                    }
+                    // 交点在端点上
+                    let interior = EPSILON..=1. - EPSILON;
+                    if !interior.contains(&t) || !interior.contains(&u) {
+                        return None;
+                    }
+                    let id = crossings.len();
+                    crossings.push(Crossing {
+                        pt: Point::new(a.x() + r.0 * t, a.y() + r.1 * t),
+                        // 从裁剪边的右侧穿到左侧
+                        entering: denom < 0.,
+                        subject: (si, 0),
+                        clip: (ci, 0),
+                    });
+                    subject_edges[si][i].push((t, id));
+                    clip_edges[ci][j].push((u, id));
+                }
+            }
+        }
+    }
+
+    let subject_seq = expand(subject, &subject_edges);
+    let clip_seq = expand(clip, &clip_edges);
+    for (seqs, is_subject) in [(&subject_seq, true), (&clip_seq, false)].iter() {
+        for (ri, seq) in seqs.iter().enumerate() {
+            for (k, node) in seq.iter().enumerate() {
+                if let Node::Crossing(id) = node {
+                    if *is_subject {
+                        crossings[*id].subject = (ri, k);
+                    } else {
+                        crossings[*id].clip = (ri, k);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut res: Vec<Vec<Point<f64>>> = vec![];
+    // 没有交点的环整体在另一方的内部或外部
+    for (ri, ring) in subject.iter().enumerate() {
+        if subject_edges[ri].iter().all(|c| c.is_empty()) && point_in_rings(clip, &ring[0]) {
+            res.push(ring.clone());
+        }
+    }
+    for (ri, ring) in clip.iter().enumerate() {
+        if clip_edges[ri].iter().all(|c| c.is_empty()) && point_in_rings(subject, &ring[0]) {
+            res.push(ring.clone());
+        }
+    }
+
+    let mut visited = vec![false; crossings.len()];
+    for start in 0..crossings.len() {
+        if visited[start] || !crossings[start].entering {
+            continue;
+        }
+        let mut ring = vec![];
+        let (mut cur, mut on_subject) = (start, true);
+        for _ in 0..=crossings.len() {
+            visited[cur] = true;
+            ring.push(crossings[cur].pt);
+            let ((ri, k), seqs) = if on_subject {
+                (crossings[cur].subject, &subject_seq)
+            } else {
+                (crossings[cur].clip, &clip_seq)
+            };
+            let seq = &seqs[ri];
+            let mut idx = (k + 1) % seq.len();
+            let next = loop {
+                match &seq[idx] {
+                    Node::Vertex(pt) => ring.push(*pt),
+                    Node::Crossing(id) => break *id,
+                }
+                idx = (idx + 1) % seq.len();
+            };
+            // 在主体上遇到的是离开点, 在裁剪边界上遇到的是进入点
+            if on_subject == crossings[next].entering {
+                return None;
+            }
+            cur = next;
+            on_subject = !on_subject;
+            if cur == start {
+                break;
+            }
+        }
+        if cur != start {
+            return None;
+        }
+        res.push(ring);
+    }
+    Some(res)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Clip, Rect};
+    use crate::base::Point2;
+    use crate::geometric::{BooleanOps, FromPoint, FromPoints, SimplePolygon, SinglePolygon};
+
+    fn u_shape() -> SimplePolygon {
+        SimplePolygon::from_points(vec![
+            (0.0, 0.0),
+            (3.0, 0.0),
+            (3.0, 3.0),
+            (2.0, 3.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 3.0),
+            (0.0, 3.0),
+        ])
+    }
+
+    #[test]
+    fn test_clip_to_rect() {
+        // 切掉 U 形的底部, 剩下两条腿
+        let rect = Rect::new(Point2::new(-1., 2.), Point2::new(4., 4.));
+        let res = u_shape().clip_to_rect(&rect);
+        assert_eq!(res.len(), 2);
+        assert!((res.area() - 2.).abs() < 1e-9);
+        // 完全在内部时不变
+        let res = u_shape().clip_to_rect(&Rect::new(Point2::new(-1., -1.), Point2::new(4., 4.)));
+        assert_eq!(res.len(), 1);
+        assert!((res.area() - 7.).abs() < 1e-9);
+        assert!(u_shape()
+            .clip_to_rect(&Rect::new(Point2::new(5., 5.), Point2::new(6., 6.)))
+            .is_empty());
+
+        // 带洞
+        let pol = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)],
+            vec![vec![(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)]],
+        );
+        let res = pol.clip_to_rect(&Rect::new(Point2::new(0.5, 0.5), Point2::new(2., 2.)));
+        assert_eq!(res.len(), 1);
+        assert!((res.area() - 1.25).abs() < 1e-9);
+        let res = pol.clip_to_rect(&Rect::new(Point2::new(0.5, 0.), Point2::new(5., 2.)));
+        assert!((res.area() - 5.).abs() < 1e-9);
+
+        let big = SimplePolygon::from_points(vec![
+            (0.0, 0.0),
+            (600.0, 0.0),
+            (600.0, 300.0),
+            (0.0, 300.0),
+        ]);
+        let tiles = big.clip_to_tiles(256.);
+        assert_eq!(tiles.len(), 6);
+        assert!((tiles.iter().map(|(_, pol)| pol.area()).sum::<f64>() - 180000.).abs() < 1e-6);
+        assert!(tiles.iter().any(|&(key, _)| key == (2, 1)));
+    }
+
+    #[test]
+    fn test_clip_by_polygon() {
+        // 凹的裁剪多边形: 横放的 U 形, 开口朝右
+        let clip = SinglePolygon::from_points(
+            vec![
+                (-0.5, 0.5),
+                (3.5, 0.5),
+                (3.5, 1.5),
+                (0.5, 1.5),
+                (0.5, 2.5),
+                (3.5, 2.5),
+                (3.5, 3.5),
+                (-0.5, 3.5),
+            ],
+            vec![],
+        );
+        let subject = SinglePolygon::from(u_shape());
+        let res = subject.clip_by(&clip);
+        let expected = subject.intersection(&clip);
+        assert!((res.area() - expected.area()).abs() < 1e-9);
+        assert_eq!(res.len(), expected.len());
+        assert_eq!(res.len(), 2);
+
+        // 交点落在顶点上时退回通用布尔运算
+        let rect = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (2.0, 0.0), (2.0, 3.0), (0.0, 3.0)],
+            vec![],
+        );
+        let res = subject.clip_by(&rect);
+        assert!((res.area() - 4.).abs() < 1e-9);
+
+        // 裁剪多边形有洞
+        let ring = SinglePolygon::from_points(
+            vec![(-1.0, -1.0), (4.0, -1.0), (4.0, 4.0), (-1.0, 4.0)],
+            vec![vec![(0.5, 0.5), (2.5, 0.5), (2.5, 2.5), (0.5, 2.5)]],
+        );
+        let res = subject.clip_by(&ring);
+        assert!((res.area() - subject.intersection(&ring).area()).abs() < 1e-9);
+
+        // 被裁剪的多边形有洞, 裁剪多边形是凹的
+        let holed = SinglePolygon::from_points(
+            vec![(-0.2, -0.2), (3.2, -0.2), (3.2, 3.2), (-0.2, 3.2)],
+            vec![vec![(1.1, 0.8), (1.1, 2.9), (2.3, 2.9), (2.3, 0.8)]],
+        );
+        let res = holed.clip_by(&clip);
+        let expected = holed.intersection(&clip);
+        assert!((res.area() - expected.area()).abs() < 1e-9);
+        assert_eq!(res.len(), expected.len());
+    }
+}
@@ -0,0 +1,133 @@
+use super::calipers::strict_ring;
+use super::ConvexPolygon;
+use crate::base::{AlmostEqual, Point2, Vector2, EPSILON};
+
+// 碰撞信息
+// normal 为从 self 指向 other 的单位法向, depth 为穿透深度
+// other 沿 mtv = normal * depth 平移后两者恰好接触
+#[derive(PartialEq, Clone, Debug)]
+pub struct Contact {
+    pub normal: Vector2,
+    pub depth: f64,
+    pub mtv: Vector2,
+}
+
+// 分离轴定理: 两个凸多边形不相交当且仅当存在某条边的法向, 使得两者在其上的投影不重叠
+// 边界接触也算相交, 此时 depth 为 0
+impl ConvexPolygon {
+    pub fn intersects(&self, other: &ConvexPolygon) -> bool {
+        self.collide(other).is_some()
+    }
+
+    pub fn collide(&self, other: &ConvexPolygon) -> Option<Contact> {
+        let (a, b) = (strict_ring(self), strict_ring(other));
+        if a.is_empty() || b.is_empty() {
+            return None;
+        }
+        let mut axes = vec![];
+        axes.extend(edge_normals(&a));
+        axes.extend(edge_normals(&b));
+        if axes.is_empty() {
+            // 两个都退化为点
+            return if a[0].almost_equal(&b[0]) {
+                Some(Contact {
+                    normal: Vector2::new(1., 0.),
+                    depth: 0.,
+                    mtv: Vector2::zero(),
+                })
+            } else {
+                None
+            };
+        }
+
+        let mut best: Option<(f64, Vector2)> = None;
+        for axis in axes {
+            let (min_a, max_a) = project(&a, &axis);
+            let (min_b, max_b) = project(&b, &axis);
+            // 沿 axis 推开 other, 或沿反方向推开
+            let (forward, backward) = (max_a - min_b, max_b - min_a);
+            if forward < -EPSILON || backward < -EPSILON {
+                return None;
+            }
+            let (depth, normal) = if forward <= backward {
+                (forward.max(0.), axis)
+            } else {
+                (backward.max(0.), -axis)
+            };
+            match best {
+                Some((d, _)) if d <= depth => {}
+                _ => best = Some((depth, normal)),
+            }
+        }
+        best.map(|(depth, normal)| Contact {
+            mtv: normal.clone() * depth,
+            normal,
+            depth,
+        })
+    }
+}
+
+// 逆时针各边的单位法向; 退化为线段时还需要线段本身的方向
+fn edge_normals(pts: &[Point2]) -> Vec<Vector2> {
+    let n = pts.len();
+    if n < 2 {
+        return vec![];
+    }
+    let mut res: Vec<Vector2> = (0..n)
+        .map(|i| pts[(i + 1) % n].from(&pts[i]).normal_vector())
+        .collect();
+    if n == 2 {
+        res.truncate(1);
+        res.push(pts[1].from(&pts[0]).normalize());
+    }
+    res
+}
+
+fn project(pts: &[Point2], axis: &Vector2) -> (f64, f64) {
+    pts.iter()
+        .map(|pt| axis.x() * pt.x() + axis.y() * pt.y())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+            (lo.min(v), hi.max(v))
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::base::Vector2;
+    use crate::geometric::ConvexPolygon;
+    use geo::LineString;
+
+    fn square(x: f64, y: f64, size: f64) -> ConvexPolygon {
+        ConvexPolygon::new(LineString::from(vec![
+            (x, y),
+            (x + size, y),
+            (x + size, y + size),
+            (x, y + size),
+        ]))
+    }
+
+    #[test]
+    fn test_collide() {
+        let a = square(0., 0., 2.);
+        let b = square(1.5, 0.5, 2.);
+        let contact = a.collide(&b).unwrap();
+        assert!((contact.depth - 0.5).abs() < 1e-9);
+        assert_eq!(contact.normal, Vector2::new(1., 0.));
+        assert!((contact.mtv.x() - 0.5).abs() < 1e-9 && contact.mtv.y().abs() < 1e-9);
+        // 反过来时法向相反
+        assert_eq!(b.collide(&a).unwrap().normal, Vector2::new(-1., 0.));
+
+        assert!(!a.intersects(&square(2.5, 0., 1.)));
+        // 边界接触
+        let contact = a.collide(&square(2., 2., 1.)).unwrap();
+        assert!(contact.depth.abs() < 1e-9);
+
+        // 三角形的斜边是分离轴, 包围盒却相交
+        let tri = ConvexPolygon::new(LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (0.0, 4.0)]));
+        assert!(!tri.intersects(&square(2.5, 2.5, 1.)));
+        let contact = tri.collide(&square(1.5, 1.5, 1.)).unwrap();
+        let s = std::f64::consts::FRAC_1_SQRT_2;
+        assert!((contact.normal.x() - s).abs() < 1e-9 && (contact.normal.y() - s).abs() < 1e-9);
+        assert!((contact.depth - s).abs() < 1e-9);
+    }
+}
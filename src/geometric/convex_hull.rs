@@ -1,5 +1,9 @@
 use super::{ConvexPolygon, SimplePolygon, SinglePolygon};
+use crate::base::point::is_ccw;
+use crate::base::{AlmostEqual, Point2};
 use geo::convexhull::ConvexHull;
+use geo::{LineString, Point};
+use std::cmp::Ordering;
 
 pub trait SimpleConvexHull {
     fn convex_hull(&self) -> ConvexPolygon;
@@ -20,3 +24,300 @@ impl SimpleConvexHull for SinglePolygon {
         }
     }
 }
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum HullMode {
+    // Andrew 单调链, O(n log n)
+    MonotoneChain,
+    // 分治, 平均 O(n log n), 最坏 O(n^2)
+    Quickhull,
+    // 按 x 排序后逐点插入, 从上一个插入点出发找两条切线, O(n log n)
+    Incremental,
+}
+
+// keep_collinear 为 true 时保留落在凸包边上的点
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct HullOptions {
+    pub mode: HullMode,
+    pub keep_collinear: bool,
+}
+
+impl Default for HullOptions {
+    fn default() -> Self {
+        HullOptions {
+            mode: HullMode::MonotoneChain,
+            keep_collinear: false,
+        }
+    }
+}
+
+// indices 为凸包顶点在输入中的下标, 从最左 (其次最下) 的点开始逆时针排列
+// 重复点只保留第一个; 所有点共线时凸包退化为线段
+#[derive(PartialEq, Clone, Debug)]
+pub struct Hull {
+    pub indices: Vec<usize>,
+    pub polygon: ConvexPolygon,
+}
+
+// 点集的凸包
+pub trait PointsConvexHull {
+    fn convex_hull_with(&self, options: &HullOptions) -> Hull;
+
+    fn convex_hull(&self) -> Hull {
+        self.convex_hull_with(&HullOptions::default())
+    }
+}
+
+impl PointsConvexHull for [Point2] {
+    fn convex_hull_with(&self, options: &HullOptions) -> Hull {
+        let indices = hull_indices(self, options);
+        let pts: Vec<Point<f64>> = indices
+            .iter()
+            .map(|&i| Point::new(self[i].x(), self[i].y()))
+            .collect();
+        Hull {
+            indices,
+            polygon: ConvexPolygon::new(LineString::from(pts)),
+        }
+    }
+}
+
+impl PointsConvexHull for [(f64, f64)] {
+    fn convex_hull_with(&self, options: &HullOptions) -> Hull {
+        let pts: Vec<Point2> = self.iter().map(|&(x, y)| Point2::new(x, y)).collect();
+        pts.convex_hull_with(options)
+    }
+}
+
+// b 处是否需要去掉: 右转, 或不保留共线点时共线
+fn is_reflex(a: &Point2, b: &Point2, c: &Point2, keep_collinear: bool) -> bool {
+    if keep_collinear {
+        is_ccw(a, c, b)
+    } else {
+        !is_ccw(a, b, c)
+    }
+}
+
+pub(crate) fn hull_indices(pts: &[Point2], options: &HullOptions) -> Vec<usize> {
+    // 按 (x, y) 排序并去重, 之后只处理排序后的位置
+    let mut order: Vec<usize> = (0..pts.len()).collect();
+    order.sort_by(|&i, &j| {
+        pts[i]
+            .partial_cmp(&pts[j])
+            .unwrap_or(Ordering::Equal)
+            .then(i.cmp(&j))
+    });
+    order.dedup_by(|a, b| pts[*a].almost_equal(&pts[*b]));
+    let sorted: Vec<&Point2> = order.iter().map(|&i| &pts[i]).collect();
+    let n = sorted.len();
+
+    let degenerate = (1..n.saturating_sub(1)).all(|k| {
+        !is_ccw(sorted[0], sorted[k], sorted[n - 1]) && !is_ccw(sorted[0], sorted[n - 1], sorted[k])
+    });
+    let res = if n < 3 || degenerate {
+        if options.keep_collinear || n < 3 {
+            (0..n).collect()
+        } else {
+            vec![0, n - 1]
+        }
+    } else {
+        match options.mode {
+            HullMode::MonotoneChain => monotone_chain(&sorted, options.keep_collinear),
+            HullMode::Quickhull => quickhull(&sorted, options.keep_collinear),
+            HullMode::Incremental => incremental(&sorted, options.keep_collinear),
+        }
+    };
+    res.into_iter().map(|k| order[k]).collect()
+}
+
+fn monotone_chain(pts: &[&Point2], keep_collinear: bool) -> Vec<usize> {
+    let n = pts.len();
+    let half = |seq: &mut dyn Iterator<Item = usize>| {
+        let mut chain: Vec<usize> = vec![];
+        for k in seq {
+            while chain.len() >= 2
+                && is_reflex(
+                    pts[chain[chain.len() - 2]],
+                    pts[chain[chain.len() - 1]],
+                    pts[k],
+                    keep_collinear,
+                )
+            {
+                chain.pop();
+            }
+            chain.push(k);
+        }
+        chain.pop();
+        chain
+    };
+    let mut res = half(&mut (0..n));
+    res.extend(half(&mut (0..n).rev()));
+    res
+}
+
+fn quickhull(pts: &[&Point2], keep_collinear: bool) -> Vec<usize> {
+    let n = pts.len();
+    let all: Vec<usize> = (0..n).collect();
+    let mut res = vec![0];
+    let (lower, on) = split(pts, 0, n - 1, &all);
+    quick_chain(pts, 0, n - 1, lower, on, keep_collinear, &mut res);
+    res.push(n - 1);
+    let (upper, on) = split(pts, n - 1, 0, &all);
+    quick_chain(pts, n - 1, 0, upper, on, keep_collinear, &mut res);
+    res
+}
+
+// 严格位于 a -> b 右侧的点, 以及线段 ab 内部的点
+fn split(pts: &[&Point2], a: usize, b: usize, candidates: &[usize]) -> (Vec<usize>, Vec<usize>) {
+    let (pa, pb) = (pts[a], pts[b]);
+    let ab = pb.from(pa);
+    let len2 = ab.square_l2_norm();
+    let mut right = vec![];
+    let mut on = vec![];
+    for &k in candidates.iter() {
+        if k == a || k == b {
+            continue;
+        }
+        if is_ccw(pa, pts[k], pb) {
+            right.push(k);
+        } else if !is_ccw(pa, pb, pts[k]) {
+            let t = pts[k].from(pa).dot(&ab) / len2;
+            if t > 0. && t < 1. {
+                on.push(k);
+            }
+        }
+    }
+    (right, on)
+}
+
+// 按逆时针顺序输出 a, b 之间的凸包顶点, 不含 a, b
+fn quick_chain(
+    pts: &[&Point2],
+    a: usize,
+    b: usize,
+    right: Vec<usize>,
+    mut on: Vec<usize>,
+    keep_collinear: bool,
+    res: &mut Vec<usize>,
+) {
+    if right.is_empty() {
+        if keep_collinear {
+            on.sort_by(|&i, &j| {
+                pts[a]
+                    .square_euclid_distance(pts[i])
+                    .partial_cmp(&pts[a].square_euclid_distance(pts[j]))
+                    .unwrap_or(Ordering::Equal)
+            });
+            res.extend(on);
+        }
+        return;
+    }
+    let ab = pts[b].from(pts[a]);
+    let far = |k: usize| ab.exterior(&pts[k].from(pts[a]));
+    let c = right
+        .iter()
+        .cloned()
+        .min_by(|&i, &j| far(i).partial_cmp(&far(j)).unwrap_or(Ordering::Equal))
+        .unwrap();
+    let (first, on) = split(pts, a, c, &right);
+    quick_chain(pts, a, c, first, on, keep_collinear, res);
+    res.push(c);
+    let (second, on) = split(pts, c, b, &right);
+    quick_chain(pts, c, b, second, on, keep_collinear, res);
+}
+
+fn incremental(pts: &[&Point2], keep_collinear: bool) -> Vec<usize> {
+    let n = pts.len();
+    // 逆时针的双向链表
+    let mut next = vec![0; n];
+    let mut prev = vec![0; n];
+
+    // 前 k 个点共线, 与第 k 个点组成初始三角形
+    let k = (2..n)
+        .find(|&k| is_ccw(pts[0], pts[1], pts[k]) || is_ccw(pts[0], pts[k], pts[1]))
+        .unwrap();
+    let mut ring: Vec<usize> = if keep_collinear {
+        (0..k).collect()
+    } else {
+        vec![0, k - 1]
+    };
+    ring.push(k);
+    if !is_ccw(pts[0], pts[k - 1], pts[k]) {
+        ring[1..].reverse();
+    }
+    for (i, &cur) in ring.iter().enumerate() {
+        let nxt = ring[(i + 1) % ring.len()];
+        next[cur] = nxt;
+        prev[nxt] = cur;
+    }
+
+    // 新点在已有点的右侧, 上一个插入的点一定在可见边链上
+    for (last, p) in (k..n).zip(k + 1..n) {
+        let mut lo = last;
+        while is_reflex(pts[prev[lo]], pts[lo], pts[p], keep_collinear) {
+            lo = prev[lo];
+        }
+        let mut hi = last;
+        while is_reflex(pts[hi], pts[next[hi]], pts[p], keep_collinear) {
+            hi = next[hi];
+        }
+        next[lo] = p;
+        prev[p] = lo;
+        next[p] = hi;
+        prev[hi] = p;
+    }
+
+    let mut res = vec![0];
+    let mut cur = next[0];
+    while cur != 0 {
+        res.push(cur);
+        cur = next[cur];
+    }
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::{HullMode, HullOptions, PointsConvexHull};
+    use crate::base::Point2;
+
+    #[test]
+    fn test_points_convex_hull() {
+        // 3 x 3 网格加一个重复点, 边上有共线点
+        let mut pts = vec![];
+        for i in 0..3 {
+            for j in 0..3 {
+                pts.push((i as f64, j as f64));
+            }
+        }
+        pts.push((2.0, 2.0));
+        for &mode in [
+            HullMode::MonotoneChain,
+            HullMode::Quickhull,
+            HullMode::Incremental,
+        ]
+        .iter()
+        {
+            let hull = pts.convex_hull_with(&HullOptions {
+                mode,
+                keep_collinear: false,
+            });
+            assert_eq!(hull.indices, vec![0, 6, 8, 2], "{:?}", mode);
+            assert!((hull.polygon.area() - 4.).abs() < 1e-9);
+
+            let hull = pts.convex_hull_with(&HullOptions {
+                mode,
+                keep_collinear: true,
+            });
+            assert_eq!(hull.indices, vec![0, 3, 6, 7, 8, 5, 2, 1], "{:?}", mode);
+        }
+
+        let pts: Vec<Point2> = (0..5).map(|i| Point2::new(i as f64, i as f64)).collect();
+        assert_eq!(pts.convex_hull().indices, vec![0, 4]);
+        let hull = pts.convex_hull_with(&HullOptions {
+            keep_collinear: true,
+            ..HullOptions::default()
+        });
+        assert_eq!(hull.indices, vec![0, 1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,268 @@
+use super::calipers::strict_ring;
+use super::minkowski::to_line_string;
+use super::ConvexPolygon;
+use crate::base::point::is_ccw;
+use crate::base::{AlmostEqual, Point2, Vector2, EPSILON};
+
+// 凸多边形求交与半平面裁剪
+// 结果退化为点或线段 (只有边界接触) 时返回 None
+impl ConvexPolygon {
+    // O'Rourke 线性算法: 两个多边形的边交替前进, 每次推进 "追赶" 另一方的那条边, O(n + m)
+    pub fn intersection(&self, other: &ConvexPolygon) -> Option<ConvexPolygon> {
+        let (p, q) = (strict_ring(self), strict_ring(other));
+        if p.len() < 3 || q.len() < 3 {
+            return None;
+        }
+        let res = orourke(&p, &q);
+        finish(res)
+    }
+
+    // 保留 normal 所指的一侧, 即 (x - point) · normal >= 0 的部分
+    pub fn clip_by_half_plane(&self, point: &Point2, normal: &Vector2) -> Option<ConvexPolygon> {
+        let ring = strict_ring(self);
+        if ring.len() < 3 {
+            return None;
+        }
+        let side = |pt: &Point2| pt.from(point).dot(normal);
+        let n = ring.len();
+        let mut res = vec![];
+        for i in 0..n {
+            let (a, b) = (&ring[i], &ring[(i + 1) % n]);
+            let (da, db) = (side(a), side(b));
+            if da >= 0. {
+                res.push(a.clone());
+            }
+            if (da > 0. && db < 0.) || (da < 0. && db > 0.) {
+                let t = da / (da - db);
+                res.push(a.move_along(&(b.from(a) * t)));
+            }
+        }
+        finish(res)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Inside {
+    Unknown,
+    P,
+    Q,
+}
+
+// c 在有向直线 a -> b 的哪一侧, 左侧为 1
+fn area_sign(a: &Point2, b: &Point2, c: &Point2) -> i8 {
+    let cross = b.from(a).exterior(&c.from(a));
+    if cross > EPSILON {
+        1
+    } else if cross < -EPSILON {
+        -1
+    } else {
+        0
+    }
+}
+
+// 线段 a -> b 与 c -> d 的交点, 平行时返回 None
+fn segment_intersection(a: &Point2, b: &Point2, c: &Point2, d: &Point2) -> Option<Point2> {
+    let (r, s) = (b.from(a), d.from(c));
+    let denom = r.exterior(&s);
+    if denom.abs() < EPSILON * EPSILON {
+        return None;
+    }
+    let ac = c.from(a);
+    let t = ac.exterior(&s) / denom;
+    let u = ac.exterior(&r) / denom;
+    let tol = EPSILON;
+    if t < -tol || t > 1. + tol || u < -tol || u > 1. + tol {
+        return None;
+    }
+    Some(a.move_along(&(r * t.clamp(0., 1.))))
+}
+
+fn orourke(p: &[Point2], q: &[Point2]) -> Vec<Point2> {
+    let (n, m) = (p.len(), q.len());
+    let (mut a, mut b) = (0, 0);
+    let (mut aa, mut ba) = (0, 0);
+    let mut inside = Inside::Unknown;
+    let mut first = true;
+    let mut res: Vec<Point2> = vec![];
+
+    while (aa < n || ba < m) && aa < 2 * n && ba < 2 * m {
+        let (a1, b1) = ((a + n - 1) % n, (b + m - 1) % m);
+        let edge_a = p[a].from(&p[a1]);
+        let edge_b = q[b].from(&q[b1]);
+        let cross = edge_a.exterior(&edge_b);
+        let a_hb = area_sign(&q[b1], &q[b], &p[a]);
+        let b_ha = area_sign(&p[a1], &p[a], &q[b]);
+
+        if let Some(pt) = segment_intersection(&p[a1], &p[a], &q[b1], &q[b]) {
+            if first {
+                // 第一个交点之后重新计数, 保证两个环都完整走一圈
+                aa = 0;
+                ba = 0;
+                first = false;
+            }
+            res.push(pt);
+            if a_hb > 0 {
+                inside = Inside::P;
+            } else if b_ha > 0 {
+                inside = Inside::Q;
+            }
+        }
+
+        // 两条边共线且反向: 只有边界接触
+        if cross.abs() <= EPSILON && a_hb == 0 && b_ha == 0 && edge_a.dot(&edge_b) < 0. {
+            return vec![];
+        }
+        // 两条边平行且互在对方外侧: 不相交
+        if cross.abs() <= EPSILON && a_hb < 0 && b_ha < 0 {
+            return vec![];
+        }
+
+        let advance_a = if cross.abs() <= EPSILON && a_hb == 0 && b_ha == 0 {
+            inside != Inside::P
+        } else if cross >= 0. {
+            b_ha > 0
+        } else {
+            a_hb <= 0
+        };
+        if advance_a {
+            if inside == Inside::P {
+                res.push(p[a].clone());
+            }
+            aa += 1;
+            a = (a + 1) % n;
+        } else {
+            if inside == Inside::Q {
+                res.push(q[b].clone());
+            }
+            ba += 1;
+            b = (b + 1) % m;
+        }
+    }
+
+    if inside == Inside::Unknown || res.len() < 3 {
+        // 没有真正的交叉: 包含或相离
+        if q.iter().all(|pt| contains(p, pt)) {
+            return q.to_vec();
+        }
+        if p.iter().all(|pt| contains(q, pt)) {
+            return p.to_vec();
+        }
+        return vec![];
+    }
+    res
+}
+
+// 点在逆时针凸环内或边界上
+fn contains(ring: &[Point2], pt: &Point2) -> bool {
+    let n = ring.len();
+    (0..n).all(|i| area_sign(&ring[i], &ring[(i + 1) % n], pt) >= 0)
+}
+
+// 去掉重复点和共线点, 不足三个顶点时返回 None
+fn finish(mut ring: Vec<Point2>) -> Option<ConvexPolygon> {
+    ring.dedup_by(|a, b| a.almost_equal(b));
+    while ring.len() > 1 && ring[0].almost_equal(&ring[ring.len() - 1]) {
+        ring.pop();
+    }
+    let mut k = 0;
+    while ring.len() >= 3 && k < ring.len() {
+        let len = ring.len();
+        if is_ccw(&ring[(k + len - 1) % len], &ring[k], &ring[(k + 1) % len]) {
+            k += 1;
+        } else {
+            ring.remove(k);
+            k = k.saturating_sub(1);
+        }
+    }
+    if ring.len() < 3 {
+        return None;
+    }
+    Some(ConvexPolygon::new(to_line_string(&ring)))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::base::{Point2, Vector2};
+    use crate::geometric::calipers::strict_ring;
+    use crate::geometric::{ConvexPolygon, PointsConvexHull};
+    use geo::LineString;
+
+    fn square(x: f64, y: f64, size: f64) -> ConvexPolygon {
+        ConvexPolygon::new(LineString::from(vec![
+            (x, y),
+            (x + size, y),
+            (x + size, y + size),
+            (x, y + size),
+        ]))
+    }
+
+    // 逐条边做半平面裁剪, 作为对照
+    fn clip_all(subject: &ConvexPolygon, clip: &ConvexPolygon) -> Option<ConvexPolygon> {
+        let ring = strict_ring(clip);
+        let n = ring.len();
+        let mut res = Some(subject.clone());
+        for i in 0..n {
+            let normal = ring[(i + 1) % n].from(&ring[i]).normal_vector();
+            res = res?.clip_by_half_plane(&ring[i], &normal);
+        }
+        res
+    }
+
+    #[test]
+    fn test_convex_intersection() {
+        let a = square(0., 0., 2.);
+        let res = a.intersection(&square(1., 1., 2.)).unwrap();
+        assert!((res.area() - 1.).abs() < 1e-9);
+        // 包含
+        let res = a.intersection(&square(0.5, 0.5, 1.)).unwrap();
+        assert!((res.area() - 1.).abs() < 1e-9);
+        assert!((square(0.5, 0.5, 1.).intersection(&a).unwrap().area() - 1.).abs() < 1e-9);
+        // 相同
+        assert!((a.intersection(&a).unwrap().area() - 4.).abs() < 1e-9);
+        // 只有边界接触或相离
+        assert_eq!(a.intersection(&square(2., 0., 1.)), None);
+        assert_eq!(a.intersection(&square(2., 2., 1.)), None);
+        assert_eq!(a.intersection(&square(3., 0., 1.)), None);
+
+        let res = a
+            .clip_by_half_plane(&Point2::new(1., 1.), &Vector2::new(1., 1.))
+            .unwrap();
+        assert!((res.area() - 2.).abs() < 1e-9);
+        assert_eq!(
+            a.clip_by_half_plane(&Point2::new(3., 0.), &Vector2::new(1., 0.)),
+            None
+        );
+
+        // 整数偏移下边共线, 顶点重合的情况
+        let area = |res: Option<ConvexPolygon>| res.map_or(0., |pol| pol.area());
+        let tri = ConvexPolygon::new(LineString::from(vec![(0.0, 0.0), (2.0, 0.0), (0.0, 2.0)]));
+        for i in -3..=3 {
+            for j in -3..=3 {
+                let (x, y) = (i as f64 / 2., j as f64 / 2.);
+                for other in [square(x, y, 1.), square(x, y, 2.)].iter() {
+                    for pol in [&a, &tri].iter() {
+                        let expected = area(clip_all(pol, other));
+                        assert!((area(pol.intersection(other)) - expected).abs() < 1e-9);
+                        assert!((area(other.intersection(pol)) - expected).abs() < 1e-9);
+                    }
+                }
+            }
+        }
+
+        let mut seed: u64 = 5;
+        let mut rand = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 33) as f64 / (1u64 << 31) as f64 * 4.
+        };
+        for _ in 0..200 {
+            let pts: Vec<Point2> = (0..8).map(|_| Point2::new(rand(), rand())).collect();
+            let others: Vec<Point2> = (0..8)
+                .map(|_| Point2::new(rand() + 1.5, rand() + 0.5))
+                .collect();
+            let (p, q) = (pts.convex_hull().polygon, others.convex_hull().polygon);
+            let expected = area(clip_all(&p, &q));
+            assert!((area(p.intersection(&q)) - expected).abs() < 1e-6);
+            assert!((area(q.intersection(&p)) - expected).abs() < 1e-6);
+        }
+    }
+}
@@ -1,8 +1,11 @@
-use super::utils::to_point_list;
+use super::simplicity::find_self_intersection;
+use super::utils::{ring_edges, to_point_list, to_ring};
+use super::SimplePolygon;
+use crate::base::Segment2;
 use geo::area::Area;
 use geo::{LineString, Point, Polygon};
 use serde::ser::SerializeSeq;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 
 // 简单多边形
@@ -31,8 +34,12 @@ impl ConvexPolygon {
         to_point_list(self.exterior())
     }
 
+    pub fn edges(&self) -> impl Iterator<Item = Segment2> + '_ {
+        ring_edges(self.exterior())
+    }
+
     pub fn is_simple(&self) -> bool {
-        true
+        find_self_intersection(&to_ring(self.exterior())).is_none()
     }
 
     pub fn has_hole(&self) -> bool {
@@ -78,3 +85,38 @@ impl Serialize for ConvexPolygon {
         seq.end()
     }
 }
+
+impl<'de> Deserialize<'de> for ConvexPolygon {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let pol = SimplePolygon::deserialize(deserializer)?;
+        if !pol.is_convex() {
+            return Err(de::Error::custom("polygon is not convex"));
+        }
+        Ok(ConvexPolygon {
+            _base_polygon: pol._base_polygon,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConvexPolygon;
+    use geo::LineString;
+
+    #[test]
+    fn test_convex_polygon_serde() {
+        let pol = ConvexPolygon::new(LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0)]));
+        let text = serde_json::to_string(&pol).unwrap();
+        let res: ConvexPolygon = serde_json::from_str(&text).unwrap();
+        assert_eq!(res, pol);
+
+        assert!(serde_json::from_str::<ConvexPolygon>("[]").is_err());
+        assert!(serde_json::from_str::<ConvexPolygon>("[0, 0, 1, 1]").is_err());
+        // 凹多边形和自相交的环
+        assert!(serde_json::from_str::<ConvexPolygon>("[0, 0, 4, 0, 4, 4, 1, 1, 0, 4]").is_err());
+        assert!(serde_json::from_str::<ConvexPolygon>("[0, 0, 2, 2, 2, 0, 0, 2]").is_err());
+    }
+}
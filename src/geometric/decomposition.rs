@@ -0,0 +1,369 @@
+use super::triangulate::ear_clip;
+use super::utils::{cross, orientation, oriented_rings, segments_intersect};
+use super::{ConvexPolygon, SimplePolygon};
+use geo::{LineString, Point};
+use std::collections::HashMap;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum DecompositionMode {
+    // 三角剖分后删除多余的对角线, 块数不超过最优解的 4 倍
+    HertelMehlhorn,
+    // 在凹顶点处递归切分, 必要时引入 Steiner 点, 块数接近最优
+    Bayazit,
+}
+
+pub trait ConvexDecomposition {
+    fn convex_decomposition_with(&self, mode: DecompositionMode) -> Vec<ConvexPolygon>;
+
+    fn convex_decomposition(&self) -> Vec<ConvexPolygon> {
+        self.convex_decomposition_with(DecompositionMode::HertelMehlhorn)
+    }
+}
+
+impl ConvexDecomposition for SimplePolygon {
+    fn convex_decomposition_with(&self, mode: DecompositionMode) -> Vec<ConvexPolygon> {
+        let mut ring = oriented_rings(&self._base_polygon).remove(0);
+        ring.dedup();
+        if ring.len() < 3 {
+            return vec![];
+        }
+        let pieces = match mode {
+            DecompositionMode::HertelMehlhorn => hertel_mehlhorn(&ring)
+                .into_iter()
+                .map(|piece| piece.into_iter().map(|i| ring[i]).collect())
+                .collect(),
+            DecompositionMode::Bayazit => {
+                let mut res = vec![];
+                bayazit(ring, &mut res, 0);
+                res
+            }
+        };
+        pieces
+            .into_iter()
+            .map(|pts: Vec<Point<f64>>| ConvexPolygon::new(LineString::from(pts)))
+            .collect()
+    }
+}
+
+// 合并后 cur 处的内角不超过 180 度
+fn is_convex_at(ring: &[Point<f64>], prev: usize, cur: usize, next: usize) -> bool {
+    orientation(&ring[prev], &ring[cur], &ring[next]) >= 0
+}
+
+fn hertel_mehlhorn(ring: &[Point<f64>]) -> Vec<Vec<usize>> {
+    let mut pieces: Vec<Option<Vec<usize>>> = ear_clip(&[ring.to_vec()])
+        .into_iter()
+        .map(|tri| Some(tri.to_vec()))
+        .collect();
+
+    // 有向边 -> 所在的块
+    let mut owner: HashMap<(usize, usize), usize> = HashMap::new();
+    for (k, piece) in pieces.iter().enumerate() {
+        let piece = piece.as_ref().unwrap();
+        for i in 0..3 {
+            owner.insert((piece[i], piece[(i + 1) % 3]), k);
+        }
+    }
+    let mut diagonals: Vec<(usize, usize)> = owner
+        .keys()
+        .filter(|&&(u, v)| u < v && owner.contains_key(&(v, u)))
+        .cloned()
+        .collect();
+    diagonals.sort_unstable();
+
+    for (u, v) in diagonals.into_iter() {
+        let (p, q) = match (owner.get(&(u, v)), owner.get(&(v, u))) {
+            (Some(&p), Some(&q)) if p != q => (p, q),
+            _ => continue,
+        };
+        let (pp, qq) = (pieces[p].as_ref().unwrap(), pieces[q].as_ref().unwrap());
+        // pp 中含 u -> v, 旋转为 v ... u; qq 中含 v -> u, 旋转为 u ... v
+        let rotate = |piece: &Vec<usize>, start: usize| {
+            let pos = piece.iter().position(|&i| i == start).unwrap();
+            let mut res = piece[pos..].to_vec();
+            res.extend_from_slice(&piece[..pos]);
+            res
+        };
+        let (a, b) = (rotate(pp, v), rotate(qq, u));
+        let (na, nb) = (a.len(), b.len());
+        if !is_convex_at(ring, a[na - 2], u, b[1]) || !is_convex_at(ring, b[nb - 2], v, a[1]) {
+            continue;
+        }
+        let mut merged = a;
+        merged.extend_from_slice(&b[1..nb - 1]);
+        let m = merged.len();
+        for i in 0..m {
+            owner.insert((merged[i], merged[(i + 1) % m]), p);
+        }
+        owner.remove(&(u, v));
+        owner.remove(&(v, u));
+        pieces[p] = Some(merged);
+        pieces[q] = None;
+    }
+    pieces.into_iter().flatten().collect()
+}
+
+fn at(ring: &[Point<f64>], i: isize) -> Point<f64> {
+    let n = ring.len() as isize;
+    ring[(((i % n) + n) % n) as usize]
+}
+
+fn line_intersection(
+    p1: &Point<f64>,
+    p2: &Point<f64>,
+    q1: &Point<f64>,
+    q2: &Point<f64>,
+) -> Option<Point<f64>> {
+    let d = (p2.x() - p1.x()) * (q2.y() - q1.y()) - (p2.y() - p1.y()) * (q2.x() - q1.x());
+    if d.abs() < 1e-12 {
+        return None;
+    }
+    let t = ((q1.x() - p1.x()) * (q2.y() - q1.y()) - (q1.y() - p1.y()) * (q2.x() - q1.x())) / d;
+    Some(Point::new(
+        p1.x() + t * (p2.x() - p1.x()),
+        p1.y() + t * (p2.y() - p1.y()),
+    ))
+}
+
+// 顶点 i 与 j 的连线是否在多边形内部且不与其他边相交
+fn can_see(ring: &[Point<f64>], i: usize, j: usize) -> bool {
+    let n = ring.len();
+    let (a, b) = (ring[i], ring[j]);
+    // 连线需在 i 处的内角中
+    let (prev, next) = (ring[(i + n - 1) % n], ring[(i + 1) % n]);
+    let inside = if orientation(&prev, &a, &next) >= 0 {
+        cross(&prev, &a, &b) > 0. && cross(&a, &next, &b) > 0.
+    } else {
+        cross(&prev, &a, &b) > 0. || cross(&a, &next, &b) > 0.
+    };
+    if !inside {
+        return false;
+    }
+    for k in 0..n {
+        let l = (k + 1) % n;
+        if k == i || l == i || k == j || l == j {
+            continue;
+        }
+        if segments_intersect(&a, &b, &ring[k], &ring[l]) {
+            return false;
+        }
+    }
+    true
+}
+
+// Bayazit 凸分解, ring 为逆时针
+fn bayazit(ring: Vec<Point<f64>>, res: &mut Vec<Vec<Point<f64>>>, depth: usize) {
+    let n = ring.len();
+    if n < 3 {
+        return;
+    }
+    if depth > 2 * n + 64 {
+        push_convex(ring, res);
+        return;
+    }
+    for i in 0..n {
+        let ii = i as isize;
+        if orientation(&at(&ring, ii - 1), &ring[i], &at(&ring, ii + 1)) >= 0 {
+            continue;
+        }
+        // 延长两条邻边, 分别求与多边形最近的交点
+        let (mut lower_dist, mut upper_dist) = (f64::INFINITY, f64::INFINITY);
+        let (mut lower_int, mut upper_int) = (ring[i], ring[i]);
+        let (mut lower_index, mut upper_index) = (0usize, 0usize);
+        for j in 0..n {
+            let jj = j as isize;
+            let (prev, cur, next) = (at(&ring, ii - 1), ring[i], at(&ring, ii + 1));
+            if cross(&prev, &cur, &at(&ring, jj)) > 0.
+                && cross(&prev, &cur, &at(&ring, jj - 1)) <= 0.
+            {
+                if let Some(p) = line_intersection(&prev, &cur, &at(&ring, jj), &at(&ring, jj - 1))
+                {
+                    if cross(&next, &cur, &p) < 0. {
+                        let d = (p.x() - cur.x()).powi(2) + (p.y() - cur.y()).powi(2);
+                        if d < lower_dist {
+                            lower_dist = d;
+                            lower_int = p;
+                            lower_index = j;
+                        }
+                    }
+                }
+            }
+            if cross(&next, &cur, &at(&ring, jj + 1)) > 0.
+                && cross(&next, &cur, &at(&ring, jj)) <= 0.
+            {
+                if let Some(p) = line_intersection(&next, &cur, &at(&ring, jj), &at(&ring, jj + 1))
+                {
+                    if cross(&prev, &cur, &p) > 0. {
+                        let d = (p.x() - cur.x()).powi(2) + (p.y() - cur.y()).powi(2);
+                        if d < upper_dist {
+                            upper_dist = d;
+                            upper_int = p;
+                            upper_index = j;
+                        }
+                    }
+                }
+            }
+        }
+        if !lower_dist.is_finite() || !upper_dist.is_finite() {
+            continue;
+        }
+
+        let (lower, upper) = if lower_index == (upper_index + 1) % n {
+            // 两个交点间没有顶点, 在中点处切分
+            let p = Point::new(
+                (lower_int.x() + upper_int.x()) / 2.,
+                (lower_int.y() + upper_int.y()) / 2.,
+            );
+            let mut lower = vec![];
+            let mut upper = vec![p];
+            if i < upper_index {
+                lower.extend_from_slice(&ring[i..=upper_index]);
+                lower.push(p);
+                if lower_index != 0 {
+                    upper.extend_from_slice(&ring[lower_index..]);
+                }
+                upper.extend_from_slice(&ring[..=i]);
+            } else {
+                lower.extend_from_slice(&ring[i..]);
+                lower.extend_from_slice(&ring[..=upper_index]);
+                lower.push(p);
+                upper.extend_from_slice(&ring[lower_index..=i]);
+            }
+            (lower, upper)
+        } else {
+            // 连接到两条延长线之间可见且最近的顶点
+            let upper_end = if lower_index > upper_index {
+                upper_index + n
+            } else {
+                upper_index
+            };
+            let mut closest: Option<(f64, usize)> = None;
+            let (prev, cur, next) = (at(&ring, ii - 1), ring[i], at(&ring, ii + 1));
+            for j in lower_index..=upper_end {
+                let pj = at(&ring, j as isize);
+                let jm = j % n;
+                if jm == i || jm == (i + 1) % n || jm == (i + n - 1) % n {
+                    continue;
+                }
+                if cross(&prev, &cur, &pj) >= 0.
+                    && cross(&next, &cur, &pj) <= 0.
+                    && can_see(&ring, i, jm)
+                {
+                    let d = (pj.x() - cur.x()).powi(2) + (pj.y() - cur.y()).powi(2);
+                    if closest.map_or(true, |(best, _)| d < best) {
+                        closest = Some((d, jm));
+                    }
+                }
+            }
+            let c = match closest {
+                Some((_, c)) => c,
+                None => match (0..n).find(|&j| {
+                    j != i && j != (i + 1) % n && j != (i + n - 1) % n && can_see(&ring, i, j)
+                }) {
+                    Some(c) => c,
+                    None => continue,
+                },
+            };
+            let mut lower = vec![];
+            let mut upper = vec![];
+            if i < c {
+                lower.extend_from_slice(&ring[i..=c]);
+                upper.extend_from_slice(&ring[c..]);
+                upper.extend_from_slice(&ring[..=i]);
+            } else {
+                lower.extend_from_slice(&ring[i..]);
+                lower.extend_from_slice(&ring[..=c]);
+                upper.extend_from_slice(&ring[c..=i]);
+            }
+            (lower, upper)
+        };
+        if lower.len() < 3 || upper.len() < 3 {
+            continue;
+        }
+        if lower.len() < upper.len() {
+            bayazit(lower, res, depth + 1);
+            bayazit(upper, res, depth + 1);
+        } else {
+            bayazit(upper, res, depth + 1);
+            bayazit(lower, res, depth + 1);
+        }
+        return;
+    }
+    // 所有凹顶点都无法切分
+    push_convex(ring, res);
+}
+
+// 回退路径留下的环可能仍是凹的, 改用 Hertel-Mehlhorn 切分
+fn push_convex(ring: Vec<Point<f64>>, res: &mut Vec<Vec<Point<f64>>>) {
+    let n = ring.len();
+    if (0..n).all(|i| is_convex_at(&ring, (i + n - 1) % n, i, (i + 1) % n)) {
+        res.push(ring);
+        return;
+    }
+    for piece in hertel_mehlhorn(&ring) {
+        res.push(piece.into_iter().map(|i| ring[i]).collect());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ConvexDecomposition, DecompositionMode};
+    use crate::geometric::{FromPoint, SimplePolygon};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_convex_decomposition() {
+        let pol = SimplePolygon::from_points(vec![
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 4.0),
+            (0.0, 4.0),
+        ]);
+        for &mode in [
+            DecompositionMode::HertelMehlhorn,
+            DecompositionMode::Bayazit,
+        ]
+        .iter()
+        {
+            let pieces = pol.convex_decomposition_with(mode);
+            assert_eq!(pieces.len(), 2);
+            let area: f64 = pieces.iter().map(|p| p.area().abs()).sum();
+            assert!((area - 7.).abs() < 1e-9);
+            assert!(pieces.iter().all(|p| p._base_polygon.is_convex()));
+        }
+
+        let square =
+            SimplePolygon::from_points(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        assert_eq!(square.convex_decomposition().len(), 1);
+    }
+
+    #[test]
+    fn test_bayazit_random() {
+        let mut seed: u64 = 7;
+        let mut rand = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 33) as f64 / (1u64 << 31) as f64
+        };
+        // 角度均匀加扰动、半径随机的星形多边形总是简单多边形
+        for _ in 0..200 {
+            let n = 5 + (rand() * 20.) as usize;
+            let angles: Vec<f64> = (0..n)
+                .map(|k| (k as f64 + rand() * 0.8) / n as f64 * 2. * PI)
+                .collect();
+            let pts: Vec<(f64, f64)> = angles
+                .iter()
+                .map(|a| {
+                    let r = 1. + rand() * 9.;
+                    (r * a.cos(), r * a.sin())
+                })
+                .collect();
+            let pol = SimplePolygon::from_points(pts);
+            let pieces = pol.convex_decomposition_with(DecompositionMode::Bayazit);
+            assert!(pieces.iter().all(|p| p._base_polygon.is_convex()));
+            let area: f64 = pieces.iter().map(|p| p.area().abs()).sum();
+            assert!((area - pol.area().abs()).abs() < 1e-6 * pol.area().abs().max(1.));
+        }
+    }
+}
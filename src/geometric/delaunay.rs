@@ -0,0 +1,692 @@
+use super::draw::Draw;
+use super::triangulate::ear_clip;
+use super::utils::{cross, orientation, oriented_rings};
+use super::{SimplePolygon, SinglePolygon};
+use crate::base::{Color, EPSILON};
+use geo::Point;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+// 约束 Delaunay 三角剖分的参数
+// min_angle 为角度制, 设置 min_angle 或 max_area 时进行 Ruppert 加密
+#[derive(Clone, Debug)]
+pub struct DelaunayOptions {
+    pub steiner_points: Vec<Point<f64>>,
+    pub min_angle: Option<f64>,
+    pub max_area: Option<f64>,
+    // 加密时最多插入的点数, 防止小角输入时不终止
+    pub max_steiner: usize,
+}
+
+impl Default for DelaunayOptions {
+    fn default() -> Self {
+        DelaunayOptions {
+            steiner_points: vec![],
+            min_angle: None,
+            max_area: None,
+            max_steiner: 10000,
+        }
+    }
+}
+
+pub trait ConstrainedDelaunay {
+    fn constrained_delaunay(&self, options: &DelaunayOptions) -> Mesh;
+
+    fn delaunay(&self) -> Mesh {
+        self.constrained_delaunay(&DelaunayOptions::default())
+    }
+}
+
+impl ConstrainedDelaunay for SinglePolygon {
+    fn constrained_delaunay(&self, options: &DelaunayOptions) -> Mesh {
+        let mut mesh = Mesh::from_rings(&oriented_rings(&self._base_polygon));
+        mesh.apply(options);
+        mesh
+    }
+}
+
+impl ConstrainedDelaunay for SimplePolygon {
+    fn constrained_delaunay(&self, options: &DelaunayOptions) -> Mesh {
+        let mut mesh = Mesh::from_rings(&oriented_rings(&self._base_polygon));
+        mesh.apply(options);
+        mesh
+    }
+}
+
+// 三角网格, 三角形均为逆时针
+// neighbors[t][i] 为三角形 t 中顶点 i 对边另一侧的三角形
+#[derive(Clone, Debug, Default)]
+pub struct Mesh {
+    points: Vec<Point<f64>>,
+    triangles: Vec<[usize; 3]>,
+    neighbors: Vec<[Option<usize>; 3]>,
+    constraints: HashSet<(usize, usize)>,
+}
+
+enum Location {
+    Inside(usize),
+    OnEdge(usize, usize),
+    OnVertex,
+    Outside(usize, usize),
+}
+
+fn edge_key(a: usize, b: usize) -> (usize, usize) {
+    (a.min(b), a.max(b))
+}
+
+// d 在三角形 abc (逆时针) 外接圆内时为正
+fn in_circle(a: &Point<f64>, b: &Point<f64>, c: &Point<f64>, d: &Point<f64>) -> f64 {
+    let (adx, ady) = (a.x() - d.x(), a.y() - d.y());
+    let (bdx, bdy) = (b.x() - d.x(), b.y() - d.y());
+    let (cdx, cdy) = (c.x() - d.x(), c.y() - d.y());
+    (adx * adx + ady * ady) * (bdx * cdy - cdx * bdy)
+        - (bdx * bdx + bdy * bdy) * (adx * cdy - cdx * ady)
+        + (cdx * cdx + cdy * cdy) * (adx * bdy - bdx * ady)
+}
+
+fn circumcenter(a: &Point<f64>, b: &Point<f64>, c: &Point<f64>) -> Point<f64> {
+    let (bx, by) = (b.x() - a.x(), b.y() - a.y());
+    let (cx, cy) = (c.x() - a.x(), c.y() - a.y());
+    let d = 2. * (bx * cy - by * cx);
+    let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+    Point::new(
+        a.x() + (cy * b2 - by * c2) / d,
+        a.y() + (bx * c2 - cx * b2) / d,
+    )
+}
+
+impl Mesh {
+    pub fn points(&self) -> &[Point<f64>] {
+        &self.points
+    }
+
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    pub fn neighbors(&self) -> &[[Option<usize>; 3]] {
+        &self.neighbors
+    }
+
+    pub fn len(&self) -> usize {
+        self.triangles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    // 边 ab 是否为约束边 (外环或洞的边)
+    pub fn is_constrained(&self, a: usize, b: usize) -> bool {
+        self.constraints.contains(&edge_key(a, b))
+    }
+
+    pub fn constraints(&self) -> Vec<(usize, usize)> {
+        let mut res: Vec<(usize, usize)> = self.constraints.iter().cloned().collect();
+        res.sort_unstable();
+        res
+    }
+
+    pub fn triangle(&self, t: usize) -> [Point<f64>; 3] {
+        let tri = self.triangles[t];
+        [
+            self.points[tri[0]],
+            self.points[tri[1]],
+            self.points[tri[2]],
+        ]
+    }
+
+    pub fn area(&self) -> f64 {
+        (0..self.len()).map(|t| self.triangle_area(t)).sum()
+    }
+
+    // 三角形的最小内角, 角度制
+    pub fn min_angle(&self, t: usize) -> f64 {
+        let [a, b, c] = self.triangle(t);
+        let angle = |o: &Point<f64>, p: &Point<f64>, q: &Point<f64>| {
+            let (u, v) = (
+                (p.x() - o.x(), p.y() - o.y()),
+                (q.x() - o.x(), q.y() - o.y()),
+            );
+            (u.0 * v.1 - u.1 * v.0).abs().atan2(u.0 * v.0 + u.1 * v.1)
+        };
+        angle(&a, &b, &c)
+            .min(angle(&b, &c, &a))
+            .min(angle(&c, &a, &b))
+            .to_degrees()
+    }
+
+    fn triangle_area(&self, t: usize) -> f64 {
+        let [a, b, c] = self.triangle(t);
+        cross(&a, &b, &c) / 2.
+    }
+
+    // 由耳切结果建立网格, 再翻转为约束 Delaunay
+    fn from_rings(rings: &[Vec<Point<f64>>]) -> Mesh {
+        let mut mesh = Mesh::default();
+        for ring in rings.iter() {
+            let start = mesh.points.len();
+            let n = ring.len();
+            mesh.points.extend(ring.iter().cloned());
+            for i in 0..n {
+                let (a, b) = (start + i, start + (i + 1) % n);
+                if ring[i] != ring[(i + 1) % n] {
+                    mesh.constraints.insert(edge_key(a, b));
+                }
+            }
+        }
+        mesh.triangles = ear_clip(rings);
+        mesh.neighbors = vec![[None; 3]; mesh.triangles.len()];
+        let all: Vec<usize> = (0..mesh.triangles.len()).collect();
+        mesh.relink(&all, &[]);
+
+        let mut stack = vec![];
+        for t in 0..mesh.len() {
+            for i in 0..3 {
+                stack.push((t, i));
+            }
+        }
+        mesh.legalize(stack);
+
+        // 耳切时去掉的共线顶点重新插入
+        let mut used = vec![false; mesh.points.len()];
+        for tri in mesh.triangles.iter() {
+            for &v in tri.iter() {
+                used[v] = true;
+            }
+        }
+        for (v, used) in used.into_iter().enumerate() {
+            if !used && !mesh.is_empty() {
+                let pt = mesh.points[v];
+                if let Location::OnEdge(t, i) = mesh.locate(&pt, 0) {
+                    mesh.split_edge(t, i, v);
+                }
+            }
+        }
+        mesh
+    }
+
+    fn apply(&mut self, options: &DelaunayOptions) {
+        for pt in options.steiner_points.iter() {
+            self.insert(pt);
+        }
+        if options.min_angle.is_some() || options.max_area.is_some() {
+            self.refine(options);
+        }
+    }
+
+    // 插入一个点, 返回是否插入成功, 区域外或与已有顶点重合时忽略
+    pub fn insert(&mut self, pt: &Point<f64>) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        match self.locate(pt, self.len() - 1) {
+            Location::Inside(t) => {
+                let v = self.push_point(pt);
+                self.split_triangle(t, v);
+                true
+            }
+            Location::OnEdge(t, i) => {
+                let v = self.push_point(pt);
+                self.split_edge(t, i, v);
+                true
+            }
+            Location::OnVertex | Location::Outside(_, _) => false,
+        }
+    }
+
+    fn push_point(&mut self, pt: &Point<f64>) -> usize {
+        self.points.push(*pt);
+        self.points.len() - 1
+    }
+
+    fn edge(&self, t: usize, i: usize) -> (usize, usize) {
+        let tri = self.triangles[t];
+        (tri[(i + 1) % 3], tri[(i + 2) % 3])
+    }
+
+    fn is_fixed(&self, t: usize, i: usize) -> bool {
+        let (b, c) = self.edge(t, i);
+        self.neighbors[t][i].is_none() || self.is_constrained(b, c)
+    }
+
+    fn locate(&self, pt: &Point<f64>, start: usize) -> Location {
+        let mut t = start;
+        // 行走穿出的边界边, 区域非凸或有洞时 pt 仍可能在别的三角形中
+        let mut exit = None;
+        for _ in 0..self.len() + 3 {
+            let tri = self.triangles[t];
+            let mut next = None;
+            for i in 0..3 {
+                let (b, c) = self.edge(t, i);
+                if orientation(&self.points[b], &self.points[c], pt) < 0 {
+                    match self.neighbors[t][i] {
+                        Some(n) => next = Some(n),
+                        None => exit = Some((t, i)),
+                    }
+                    break;
+                }
+            }
+            match next {
+                Some(n) => t = n,
+                None if exit.is_some() => break,
+                None => return self.classify(t, &tri, pt),
+            }
+        }
+        // 走出边界或行走不收敛时逐个检查
+        for t in 0..self.len() {
+            let tri = self.triangles[t];
+            if (0..3).all(|i| {
+                let (b, c) = self.edge(t, i);
+                orientation(&self.points[b], &self.points[c], pt) >= 0
+            }) {
+                return self.classify(t, &tri, pt);
+            }
+        }
+        let (t, i) = exit.unwrap_or((start, 0));
+        Location::Outside(t, i)
+    }
+
+    fn classify(&self, t: usize, tri: &[usize; 3], pt: &Point<f64>) -> Location {
+        for &v in tri.iter() {
+            let p = &self.points[v];
+            if (p.x() - pt.x()).abs() + (p.y() - pt.y()).abs() < EPSILON {
+                return Location::OnVertex;
+            }
+        }
+        for i in 0..3 {
+            let (b, c) = self.edge(t, i);
+            if orientation(&self.points[b], &self.points[c], pt) == 0 {
+                return Location::OnEdge(t, i);
+            }
+        }
+        Location::Inside(t)
+    }
+
+    // 重新建立 tris 与 tris, outer 之间的邻接关系
+    fn relink(&mut self, tris: &[usize], outer: &[usize]) {
+        let mut edges: HashMap<(usize, usize), Vec<(usize, usize)>> = HashMap::new();
+        for &t in tris.iter().chain(outer.iter()) {
+            for i in 0..3 {
+                let (b, c) = self.edge(t, i);
+                edges.entry(edge_key(b, c)).or_default().push((t, i));
+            }
+        }
+        for &t in tris.iter() {
+            self.neighbors[t] = [None; 3];
+        }
+        for &t in tris.iter() {
+            for i in 0..3 {
+                let (b, c) = self.edge(t, i);
+                if let Some(&(n, j)) = edges[&edge_key(b, c)].iter().find(|&&(n, _)| n != t) {
+                    self.neighbors[t][i] = Some(n);
+                    self.neighbors[n][j] = Some(t);
+                }
+            }
+        }
+    }
+
+    fn outer_neighbors(&self, tris: &[usize]) -> Vec<usize> {
+        tris.iter()
+            .flat_map(|&t| self.neighbors[t].iter().filter_map(|n| *n))
+            .filter(|n| !tris.contains(n))
+            .collect()
+    }
+
+    fn split_triangle(&mut self, t: usize, v: usize) {
+        let outer = self.outer_neighbors(&[t]);
+        let [a, b, c] = self.triangles[t];
+        let (t1, t2) = (self.len(), self.len() + 1);
+        self.triangles[t] = [a, b, v];
+        self.triangles.push([b, c, v]);
+        self.triangles.push([c, a, v]);
+        self.neighbors.push([None; 3]);
+        self.neighbors.push([None; 3]);
+        self.relink(&[t, t1, t2], &outer);
+        self.legalize(vec![(t, 2), (t1, 2), (t2, 2)]);
+    }
+
+    // 在三角形 t 的第 i 条边上插入顶点 v
+    fn split_edge(&mut self, t: usize, i: usize, v: usize) {
+        let (b, c) = self.edge(t, i);
+        let a = self.triangles[t][i];
+        let fixed = self.is_fixed(t, i);
+        let n = self.neighbors[t][i];
+        let old: Vec<usize> = std::iter::once(t).chain(n).collect();
+        let outer = self.outer_neighbors(&old);
+
+        let t1 = self.len();
+        self.triangles[t] = [a, b, v];
+        self.triangles.push([a, v, c]);
+        self.neighbors.push([None; 3]);
+        let mut tris = vec![t, t1];
+        let mut stack = vec![(t, 2), (t1, 1)];
+        if let Some(n) = n {
+            let j = (0..3)
+                .find(|&j| self.triangles[n][j] != b && self.triangles[n][j] != c)
+                .unwrap_or(0);
+            let d = self.triangles[n][j];
+            let n1 = self.len();
+            self.triangles[n] = [d, c, v];
+            self.triangles.push([d, v, b]);
+            self.neighbors.push([None; 3]);
+            tris.extend_from_slice(&[n, n1]);
+            stack.extend_from_slice(&[(n, 2), (n1, 1)]);
+        }
+        if fixed {
+            self.constraints.remove(&edge_key(b, c));
+            self.constraints.insert(edge_key(b, v));
+            self.constraints.insert(edge_key(v, c));
+        }
+        self.relink(&tris, &outer);
+        self.legalize(stack);
+    }
+
+    // 翻转三角形 t 中顶点 i 的对边, 返回新的 (t, n), t = [a, b, d], n = [d, c, a]
+    fn flip(&mut self, t: usize, i: usize) -> Option<(usize, usize)> {
+        let n = self.neighbors[t][i]?;
+        let a = self.triangles[t][i];
+        let (b, c) = self.edge(t, i);
+        let j = (0..3).find(|&j| self.triangles[n][j] != b && self.triangles[n][j] != c)?;
+        let d = self.triangles[n][j];
+        let outer = self.outer_neighbors(&[t, n]);
+        self.triangles[t] = [a, b, d];
+        self.triangles[n] = [d, c, a];
+        self.relink(&[t, n], &outer);
+        Some((t, n))
+    }
+
+    // Lawson 翻转, stack 中为 (三角形, 顶点), 检查顶点对边
+    fn legalize(&mut self, mut stack: Vec<(usize, usize)>) {
+        let mut steps = 0;
+        let limit = 64 * (self.len() + 16) * (self.len() + 16);
+        while let Some((t, i)) = stack.pop() {
+            steps += 1;
+            if steps > limit {
+                break;
+            }
+            if self.is_fixed(t, i) {
+                continue;
+            }
+            let n = match self.neighbors[t][i] {
+                Some(n) => n,
+                None => continue,
+            };
+            let (b, c) = self.edge(t, i);
+            let a = self.triangles[t][i];
+            let d = match (0..3).find(|&j| self.triangles[n][j] != b && self.triangles[n][j] != c) {
+                Some(j) => self.triangles[n][j],
+                None => continue,
+            };
+            let (pa, pb, pc, pd) = (
+                &self.points[a],
+                &self.points[b],
+                &self.points[c],
+                &self.points[d],
+            );
+            // 翻转后的两个三角形必须仍为逆时针
+            if in_circle(pa, pb, pc, pd) <= EPSILON
+                || orientation(pa, pb, pd) <= 0
+                || orientation(pd, pc, pa) <= 0
+            {
+                continue;
+            }
+            if let Some((t, n)) = self.flip(t, i) {
+                stack.push((t, 0));
+                stack.push((n, 2));
+                stack.push((t, 2));
+                stack.push((n, 0));
+            }
+        }
+    }
+
+    // 点 pt 是否在约束边 ab 的直径圆内
+    fn encroaches(&self, a: usize, b: usize, pt: &Point<f64>) -> bool {
+        let (pa, pb) = (&self.points[a], &self.points[b]);
+        let mid = ((pa.x() + pb.x()) / 2., (pa.y() + pb.y()) / 2.);
+        let r2 = ((pa.x() - pb.x()).powi(2) + (pa.y() - pb.y()).powi(2)) / 4.;
+        (pt.x() - mid.0).powi(2) + (pt.y() - mid.1).powi(2) < r2 - EPSILON
+    }
+
+    fn find_edge(&self, a: usize, b: usize) -> Option<(usize, usize)> {
+        for t in 0..self.len() {
+            for i in 0..3 {
+                let (u, v) = self.edge(t, i);
+                if edge_key(u, v) == edge_key(a, b) {
+                    return Some((t, i));
+                }
+            }
+        }
+        None
+    }
+
+    fn split_segment(&mut self, a: usize, b: usize) -> bool {
+        match self.find_edge(a, b) {
+            Some((t, i)) => {
+                let (pa, pb) = (self.points[a], self.points[b]);
+                let mid = Point::new((pa.x() + pb.x()) / 2., (pa.y() + pb.y()) / 2.);
+                let v = self.push_point(&mid);
+                self.split_edge(t, i, v);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // 对边所对角为钝角的约束边被侵占
+    fn encroached_segment(&self) -> Option<(usize, usize)> {
+        for t in 0..self.len() {
+            for i in 0..3 {
+                if !self.is_fixed(t, i) {
+                    continue;
+                }
+                let (b, c) = self.edge(t, i);
+                let a = self.triangles[t][i];
+                if self.encroaches(b, c, &self.points[a]) {
+                    return Some((b, c));
+                }
+            }
+        }
+        None
+    }
+
+    fn is_bad(&self, t: usize, options: &DelaunayOptions) -> bool {
+        options
+            .min_angle
+            .is_some_and(|angle| self.min_angle(t) < angle)
+            || options
+                .max_area
+                .is_some_and(|area| self.triangle_area(t) > area)
+    }
+
+    // Ruppert 加密: 先分裂被侵占的约束边, 再在坏三角形的外心插入点,
+    // 外心侵占约束边时改为分裂这些边
+    fn refine(&mut self, options: &DelaunayOptions) {
+        let mut budget = options.max_steiner;
+        while budget > 0 {
+            if let Some((a, b)) = self.encroached_segment() {
+                if !self.split_segment(a, b) {
+                    break;
+                }
+                budget -= 1;
+                continue;
+            }
+            let bad = match (0..self.len()).find(|&t| self.is_bad(t, options)) {
+                Some(t) => t,
+                None => break,
+            };
+            let [a, b, c] = self.triangle(bad);
+            let center = circumcenter(&a, &b, &c);
+            let encroached: Vec<(usize, usize)> = self
+                .constraints
+                .iter()
+                .filter(|&&(u, v)| self.encroaches(u, v, &center))
+                .cloned()
+                .collect();
+            let inserted = if !encroached.is_empty() {
+                encroached
+                    .into_iter()
+                    .fold(false, |res, (u, v)| self.split_segment(u, v) || res)
+            } else {
+                match self.locate(&center, bad) {
+                    Location::Inside(t) => {
+                        let v = self.push_point(&center);
+                        self.split_triangle(t, v);
+                        true
+                    }
+                    Location::OnEdge(t, i) => {
+                        let v = self.push_point(&center);
+                        self.split_edge(t, i, v);
+                        true
+                    }
+                    Location::Outside(t, i) => {
+                        let (u, v) = self.edge(t, i);
+                        self.split_segment(u, v)
+                    }
+                    Location::OnVertex => false,
+                }
+            };
+            if !inserted {
+                break;
+            }
+            budget -= 1;
+        }
+    }
+}
+
+impl Draw for Mesh {
+    fn draw(&self, ctx: Rc<web_sys::CanvasRenderingContext2d>, color: &Color, fill: bool) {
+        let tris: Vec<Vec<Point<f64>>> =
+            (0..self.len()).map(|t| self.triangle(t).to_vec()).collect();
+        tris.draw(ctx, color, fill);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ConstrainedDelaunay, DelaunayOptions, Mesh};
+    use crate::geometric::{FromPoints, SinglePolygon};
+    use geo::Point;
+
+    fn check_mesh(mesh: &Mesh) {
+        for t in 0..mesh.len() {
+            for i in 0..3 {
+                if let Some(n) = mesh.neighbors()[t][i] {
+                    assert!(mesh.neighbors()[n].contains(&Some(t)));
+                }
+            }
+            let [a, b, c] = mesh.triangle(t);
+            assert!((b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x()) > 0.);
+        }
+    }
+
+    #[test]
+    fn test_constrained_delaunay() {
+        let pol = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 1.0), (0.0, 1.0)],
+            vec![],
+        );
+        let mesh = pol.delaunay();
+        check_mesh(&mesh);
+        assert_eq!(mesh.len(), 2);
+        assert!((mesh.area() - 10.).abs() < 1e-9);
+
+        let options = DelaunayOptions {
+            steiner_points: vec![Point::new(5., 0.5), Point::new(20., 0.5)],
+            ..DelaunayOptions::default()
+        };
+        let mesh = pol.constrained_delaunay(&options);
+        check_mesh(&mesh);
+        assert_eq!(mesh.points().len(), 5);
+        assert_eq!(mesh.len(), 4);
+    }
+
+    #[test]
+    fn test_steiner_points() {
+        // 非凸区域和洞后面的点也能插入
+        let u_shape = SinglePolygon::from_points(
+            vec![
+                (0.0, 0.0),
+                (10.0, 0.0),
+                (10.0, 10.0),
+                (7.0, 10.0),
+                (7.0, 3.0),
+                (3.0, 3.0),
+                (3.0, 10.0),
+                (0.0, 10.0),
+            ],
+            vec![],
+        );
+        let holed = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![vec![(3.0, 3.0), (7.0, 3.0), (7.0, 7.0), (3.0, 7.0)]],
+        );
+        let check = |pol: &SinglePolygon, pts: &[(f64, f64)], area: f64| {
+            let base = pol.delaunay().points().len();
+            let steiner: Vec<Point<f64>> = pts.iter().map(|&(x, y)| Point::new(x, y)).collect();
+            // 洞中和区域外的点被忽略
+            let mut candidates = steiner.clone();
+            candidates.push(Point::new(5., 5.));
+            candidates.push(Point::new(12., 5.));
+            let options = DelaunayOptions {
+                steiner_points: candidates,
+                ..DelaunayOptions::default()
+            };
+            let mesh = pol.constrained_delaunay(&options);
+            check_mesh(&mesh);
+            assert_eq!(mesh.points().len(), base + steiner.len());
+            assert!(steiner.iter().all(|pt| mesh.points().contains(pt)));
+            assert!((mesh.area() - area).abs() < 1e-9);
+        };
+        let u_points = [
+            (1., 8.),
+            (8., 8.),
+            (1., 5.),
+            (9., 1.),
+            (5., 1.),
+            (2., 2.),
+            (8., 5.),
+        ];
+        check(&u_shape, &u_points, 72.);
+        let holed_points = [
+            (1., 1.),
+            (9., 1.),
+            (2., 2.),
+            (8., 8.),
+            (1., 9.),
+            (5., 1.),
+            (5., 9.),
+        ];
+        check(&holed, &holed_points, 84.);
+    }
+
+    #[test]
+    fn test_refinement() {
+        let pol = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![vec![(4.0, 4.0), (6.0, 4.0), (6.0, 6.0), (4.0, 6.0)]],
+        );
+        let options = DelaunayOptions {
+            min_angle: Some(25.),
+            max_area: Some(4.),
+            ..DelaunayOptions::default()
+        };
+        let mesh = pol.constrained_delaunay(&options);
+        check_mesh(&mesh);
+        assert!((mesh.area() - 96.).abs() < 1e-6);
+        for t in 0..mesh.len() {
+            assert!(mesh.min_angle(t) >= 25.);
+            assert!(mesh.triangle_area(t) <= 4.);
+        }
+        // 约束边仍覆盖外环和洞的边界
+        let length: f64 = mesh
+            .constraints()
+            .iter()
+            .map(|&(a, b)| {
+                let (pa, pb) = (mesh.points()[a], mesh.points()[b]);
+                ((pa.x() - pb.x()).powi(2) + (pa.y() - pb.y()).powi(2)).sqrt()
+            })
+            .sum();
+        assert!((length - 48.).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,121 @@
+use geo::LineString;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use std::fmt;
+
+// 环中的一个元素: 扁平数组中的数, [x, y] 或 {x, y}
+enum Coord {
+    Num(f64),
+    Pair(f64, f64),
+}
+
+impl<'de> Deserialize<'de> for Coord {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(CoordVisitor)
+    }
+}
+
+struct CoordVisitor;
+
+impl<'de> Visitor<'de> for CoordVisitor {
+    type Value = Coord;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number, an [x, y] pair or an {x, y} object")
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Coord, E> {
+        Ok(Coord::Num(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Coord, E> {
+        Ok(Coord::Num(v as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Coord, E> {
+        Ok(Coord::Num(v as f64))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Coord, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let x: f64 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let y: f64 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(3, &self));
+        }
+        Ok(Coord::Pair(x, y))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Coord, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let (mut x, mut y) = (None, None);
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "x" => x = Some(map.next_value::<f64>()?),
+                "y" => y = Some(map.next_value::<f64>()?),
+                _ => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        let x = x.ok_or_else(|| de::Error::missing_field("x"))?;
+        let y = y.ok_or_else(|| de::Error::missing_field("y"))?;
+        Ok(Coord::Pair(x, y))
+    }
+}
+
+// 一个环, 接受 [x0, y0, x1, y1, ...], [[x, y], ...] 与 [{x, y}, ...]
+pub(crate) struct Ring(pub(crate) LineString<f64>);
+
+impl<'de> Deserialize<'de> for Ring {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let coords = Vec::<Coord>::deserialize(deserializer)?;
+        let mut pts: Vec<(f64, f64)> = vec![];
+        if coords.iter().all(|c| matches!(c, Coord::Num(_))) {
+            if coords.len() % 2 != 0 {
+                return Err(de::Error::custom(format!(
+                    "flat coordinate array must have even length, got {}",
+                    coords.len()
+                )));
+            }
+            for pair in coords.chunks(2) {
+                if let (Coord::Num(x), Coord::Num(y)) = (&pair[0], &pair[1]) {
+                    pts.push((*x, *y));
+                }
+            }
+        } else {
+            for coord in coords.iter() {
+                match coord {
+                    Coord::Pair(x, y) => pts.push((*x, *y)),
+                    Coord::Num(_) => {
+                        return Err(de::Error::custom(
+                            "cannot mix flat numbers with point pairs in one ring",
+                        ))
+                    }
+                }
+            }
+        }
+        for (i, pt) in pts.iter().enumerate() {
+            if !pt.0.is_finite() || !pt.1.is_finite() {
+                return Err(de::Error::custom(format!(
+                    "point {} has non-finite coordinates ({}, {})",
+                    i, pt.0, pt.1
+                )));
+            }
+        }
+        Ok(Ring(LineString::from(pts)))
+    }
+}
@@ -1,5 +1,5 @@
 use super::{ConvexPolygon, SimplePolygon};
-use crate::base::Color;
+use crate::base::{Circle, Color, Line2, Linear, Point2, Ray2, Segment2, EPSILON};
 use geo::Point;
 use std::f64;
 use std::rc::Rc;
@@ -115,3 +115,60 @@ impl Draw for ConvexPolygon {
         self.vertices().draw(ctx, color, fill);
     }
 }
+
+impl Draw for Circle {
+    fn draw(&self, ctx: Rc<web_sys::CanvasRenderingContext2d>, color: &Color, fill: bool) {
+        let color_str = color.to_string();
+        ctx.set_fill_style_str(&color_str);
+        ctx.set_stroke_style_str(&color_str);
+        ctx.begin_path();
+        let center = self.center();
+        if ctx
+            .arc(center.x(), center.y(), self.radius(), 0., 2. * f64::consts::PI)
+            .is_err()
+        {
+            return;
+        }
+        ctx.stroke();
+        if fill {
+            ctx.fill();
+        }
+    }
+}
+
+// 射线和直线画到足够远处, 超出画布的部分由画布裁掉
+const FAR: f64 = 1e5;
+
+fn stroke_between(
+    ctx: Rc<web_sys::CanvasRenderingContext2d>,
+    color: &Color,
+    start: &Point2,
+    end: &Point2,
+) {
+    ctx.set_stroke_style_str(&color.to_string());
+    ctx.begin_path();
+    ctx.move_to(start.x(), start.y());
+    ctx.line_to(end.x(), end.y());
+    ctx.stroke();
+}
+
+// 线性对象没有内部, 忽略 fill
+impl Draw for Segment2 {
+    fn draw(&self, ctx: Rc<web_sys::CanvasRenderingContext2d>, color: &Color, _fill: bool) {
+        stroke_between(ctx, color, self.start(), self.end());
+    }
+}
+
+impl Draw for Ray2 {
+    fn draw(&self, ctx: Rc<web_sys::CanvasRenderingContext2d>, color: &Color, _fill: bool) {
+        let t = FAR / self.direction().l2_norm().max(EPSILON);
+        stroke_between(ctx, color, self.origin(), &self.point_at(t));
+    }
+}
+
+impl Draw for Line2 {
+    fn draw(&self, ctx: Rc<web_sys::CanvasRenderingContext2d>, color: &Color, _fill: bool) {
+        let t = FAR / self.direction().l2_norm().max(EPSILON);
+        stroke_between(ctx, color, &self.point_at(-t), &self.point_at(t));
+    }
+}
@@ -0,0 +1,77 @@
+use super::{ConvexPolygon, GeneralPolygon, SimplePolygon, SinglePolygon};
+use crate::base::circle::min_enclosing_circle;
+use crate::base::{Circle, Point2};
+use geo::Point;
+
+// 最小覆盖圆, 空点集返回 None
+// 多边形只需考虑外环顶点
+pub trait EnclosingCircle {
+    fn min_enclosing_circle(&self) -> Option<Circle>;
+}
+
+fn to_point2(pts: &[Point<f64>]) -> Vec<Point2> {
+    pts.iter().map(|pt| Point2::new(pt.x(), pt.y())).collect()
+}
+
+impl EnclosingCircle for [Point2] {
+    fn min_enclosing_circle(&self) -> Option<Circle> {
+        min_enclosing_circle(self)
+    }
+}
+
+impl EnclosingCircle for [(f64, f64)] {
+    fn min_enclosing_circle(&self) -> Option<Circle> {
+        let pts: Vec<Point2> = self.iter().map(|&(x, y)| Point2::new(x, y)).collect();
+        min_enclosing_circle(&pts)
+    }
+}
+
+impl EnclosingCircle for SimplePolygon {
+    fn min_enclosing_circle(&self) -> Option<Circle> {
+        min_enclosing_circle(&to_point2(&self.vertices()))
+    }
+}
+
+impl EnclosingCircle for SinglePolygon {
+    fn min_enclosing_circle(&self) -> Option<Circle> {
+        min_enclosing_circle(&to_point2(&self.vertices()))
+    }
+}
+
+impl EnclosingCircle for ConvexPolygon {
+    fn min_enclosing_circle(&self) -> Option<Circle> {
+        min_enclosing_circle(&to_point2(&self.vertices()))
+    }
+}
+
+impl EnclosingCircle for GeneralPolygon {
+    fn min_enclosing_circle(&self) -> Option<Circle> {
+        let pts: Vec<Point<f64>> = self.iter().flat_map(|pol| pol.vertices()).collect();
+        min_enclosing_circle(&to_point2(&pts))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EnclosingCircle;
+    use crate::base::Point2;
+    use crate::geometric::{FromPoints, GeneralPolygon, SinglePolygon};
+
+    #[test]
+    fn test_polygon_enclosing_circle() {
+        let pol = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)],
+            vec![vec![(1.0, 1.0), (1.0, 2.0), (2.0, 2.0), (2.0, 1.0)]],
+        );
+        let circle = pol.min_enclosing_circle().unwrap();
+        assert!(circle.center().euclid_distance(&Point2::new(2., 2.)) < 1e-9);
+        assert!((circle.radius() - 8f64.sqrt()).abs() < 1e-9);
+
+        let other = SinglePolygon::from_points(vec![(10.0, 0.0), (12.0, 0.0), (12.0, 4.0)], vec![]);
+        let circle = GeneralPolygon::new(vec![pol, other])
+            .min_enclosing_circle()
+            .unwrap();
+        assert!((circle.radius() - 12f64.hypot(4.) / 2.).abs() < 1e-9);
+        assert!(GeneralPolygon::default().min_enclosing_circle().is_none());
+    }
+}
@@ -0,0 +1,25 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum PolygonError {
+    // 去重后顶点数不足 3
+    TooFewVertices(usize),
+    // 边 first 与边 second 相交, 边 i 为顶点 i 到顶点 i + 1
+    SelfIntersection { first: usize, second: usize },
+}
+
+impl fmt::Display for PolygonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolygonError::TooFewVertices(num) => {
+                write!(f, "polygon needs at least 3 distinct vertices, got {}", num)
+            }
+            PolygonError::SelfIntersection { first, second } => {
+                write!(f, "polygon edges {} and {} intersect", first, second)
+            }
+        }
+    }
+}
+
+impl Error for PolygonError {}
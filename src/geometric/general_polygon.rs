@@ -1,41 +1,118 @@
-use crate::{CoordinateType, Polygon};
+use super::utils::unsigned_area;
+use super::{Draw, SinglePolygon};
+use crate::base::{Color, Segment2};
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::iter::FromIterator;
+use std::rc::Rc;
 
-/// A collection of [`Polygon`s](struct.Polygon.html).
-///
-/// Can be created from a `Vec` of `Polygon`s, or `collect`ed from an Iterator which yields `Polygon`s.
-///
-/// Iterating over this object yields the component Polygons.
-#[derive(PartialEq, Clone, Debug)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct MultiPolygon<T>(pub Vec<Polygon<T>>)
-where
-    T: CoordinateType;
+// 多个带洞多边形的集合
+// 布尔运算等操作的结果
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct GeneralPolygon {
+    pub(crate) polygons: Vec<SinglePolygon>,
+}
+
+impl GeneralPolygon {
+    pub fn new(polygons: Vec<SinglePolygon>) -> Self {
+        GeneralPolygon { polygons }
+    }
+
+    pub fn len(&self) -> usize {
+        self.polygons.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.polygons.is_empty()
+    }
+
+    pub fn polygons(&self) -> &[SinglePolygon] {
+        &self.polygons
+    }
+
+    pub fn get(&self, i: usize) -> Option<&SinglePolygon> {
+        self.polygons.get(i)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, SinglePolygon> {
+        self.polygons.iter()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = Segment2> + '_ {
+        self.polygons.iter().flat_map(|pol| pol.edges())
+    }
 
-impl<T: CoordinateType, IP: Into<Polygon<T>>> From<IP> for MultiPolygon<T> {
-    fn from(x: IP) -> Self {
-        MultiPolygon(vec![x.into()])
+    pub fn num_hole(&self) -> usize {
+        self.polygons.iter().map(|pol| pol.num_hole()).sum()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.polygons
+            .iter()
+            .map(|pol| unsigned_area(&pol._base_polygon))
+            .sum()
     }
 }
 
-impl<T: CoordinateType, IP: Into<Polygon<T>>> From<Vec<IP>> for MultiPolygon<T> {
-    fn from(x: Vec<IP>) -> Self {
-        MultiPolygon(x.into_iter().map(|p| p.into()).collect())
+impl From<SinglePolygon> for GeneralPolygon {
+    fn from(pol: SinglePolygon) -> Self {
+        GeneralPolygon {
+            polygons: vec![pol],
+        }
     }
 }
 
-impl<T: CoordinateType, IP: Into<Polygon<T>>> FromIterator<IP> for MultiPolygon<T> {
-    fn from_iter<I: IntoIterator<Item = IP>>(iter: I) -> Self {
-        MultiPolygon(iter.into_iter().map(|p| p.into()).collect())
+impl From<Vec<SinglePolygon>> for GeneralPolygon {
+    fn from(polygons: Vec<SinglePolygon>) -> Self {
+        GeneralPolygon { polygons }
     }
 }
 
-impl<T: CoordinateType> IntoIterator for MultiPolygon<T> {
-    type Item = Polygon<T>;
-    type IntoIter = ::std::vec::IntoIter<Polygon<T>>;
+impl FromIterator<SinglePolygon> for GeneralPolygon {
+    fn from_iter<I: IntoIterator<Item = SinglePolygon>>(iter: I) -> Self {
+        GeneralPolygon {
+            polygons: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl IntoIterator for GeneralPolygon {
+    type Item = SinglePolygon;
+    type IntoIter = ::std::vec::IntoIter<SinglePolygon>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.polygons.into_iter()
+    }
+}
+
+impl Draw for GeneralPolygon {
+    fn draw(&self, ctx: Rc<web_sys::CanvasRenderingContext2d>, color: &Color, fill: bool) {
+        for pol in self.polygons.iter() {
+            pol.draw(ctx.clone(), color, fill);
+        }
+    }
+}
+
+impl Serialize for GeneralPolygon {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for pol in self.polygons.iter() {
+            seq.serialize_element(pol)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GeneralPolygon {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(GeneralPolygon {
+            polygons: Vec::<SinglePolygon>::deserialize(deserializer)?,
+        })
     }
 }
-1
\ No newline at end of file
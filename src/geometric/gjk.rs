@@ -0,0 +1,466 @@
+use super::ConvexPolygon;
+use crate::base::{Circle, Point2, Vector2, EPSILON};
+use std::cmp::Ordering;
+
+// 凸形状的支撑函数: 在方向 dir 上投影最大的点
+// 距离和穿透深度都在 Minkowski 差 A ⊖ B 上计算, 只需要两者的支撑函数
+pub trait Support {
+    fn support(&self, dir: &Vector2) -> Point2;
+
+    // GJK 最近距离, 相交 (含接触) 时返回 None
+    fn distance_to<T: Support + ?Sized>(&self, other: &T) -> Option<Proximity> {
+        match gjk(self, other) {
+            Gjk::Separated(res) => Some(res),
+            Gjk::Overlap(_) => None,
+        }
+    }
+
+    // EPA 穿透深度, 不相交时返回 None
+    fn penetration<T: Support + ?Sized>(&self, other: &T) -> Option<Proximity> {
+        match gjk(self, other) {
+            Gjk::Separated(_) => None,
+            Gjk::Overlap(simplex) => Some(epa(self, other, simplex)),
+        }
+    }
+}
+
+// on_a, on_b 分别为两个形状上的见证点, normal 为从 self 指向 other 的单位方向
+// 分离时 distance 为最近距离, on_b - on_a = normal * distance
+// 相交时 distance 为穿透深度, other 沿 normal 平移 distance 后两者恰好接触
+#[derive(PartialEq, Clone, Debug)]
+pub struct Proximity {
+    pub distance: f64,
+    pub normal: Vector2,
+    pub on_a: Point2,
+    pub on_b: Point2,
+}
+
+// 平移后的形状
+pub struct Translated<'a, S: Support + ?Sized> {
+    shape: &'a S,
+    offset: Vector2,
+}
+
+impl<'a, S: Support + ?Sized> Translated<'a, S> {
+    pub fn new(shape: &'a S, offset: Vector2) -> Self {
+        Translated { shape, offset }
+    }
+}
+
+// 绕 center 逆时针旋转 angle 弧度后的形状
+pub struct Rotated<'a, S: Support + ?Sized> {
+    shape: &'a S,
+    center: Point2,
+    angle: f64,
+}
+
+impl<'a, S: Support + ?Sized> Rotated<'a, S> {
+    pub fn new(shape: &'a S, center: Point2, angle: f64) -> Self {
+        Rotated {
+            shape,
+            center,
+            angle,
+        }
+    }
+}
+
+fn rotate(v: &Vector2, angle: f64) -> Vector2 {
+    let (sin, cos) = angle.sin_cos();
+    Vector2::new(cos * v.x() - sin * v.y(), sin * v.x() + cos * v.y())
+}
+
+impl<'a, S: Support + ?Sized> Support for Translated<'a, S> {
+    fn support(&self, dir: &Vector2) -> Point2 {
+        self.shape.support(dir).move_along(&self.offset)
+    }
+}
+
+impl<'a, S: Support + ?Sized> Support for Rotated<'a, S> {
+    fn support(&self, dir: &Vector2) -> Point2 {
+        let pt = self.shape.support(&rotate(dir, -self.angle));
+        self.center
+            .move_along(&rotate(&pt.from(&self.center), self.angle))
+    }
+}
+
+impl Support for Point2 {
+    fn support(&self, _dir: &Vector2) -> Point2 {
+        self.clone()
+    }
+}
+
+// 点集的凸包
+impl Support for [Point2] {
+    fn support(&self, dir: &Vector2) -> Point2 {
+        let mut best = (f64::NEG_INFINITY, Point2::zero());
+        for pt in self.iter() {
+            let value = dir.x() * pt.x() + dir.y() * pt.y();
+            if value > best.0 {
+                best = (value, pt.clone());
+            }
+        }
+        best.1
+    }
+}
+
+impl Support for ConvexPolygon {
+    fn support(&self, dir: &Vector2) -> Point2 {
+        let mut best = (f64::NEG_INFINITY, Point2::zero());
+        for pt in self._base_polygon.exterior().points_iter() {
+            let value = dir.x() * pt.x() + dir.y() * pt.y();
+            if value > best.0 {
+                best = (value, Point2::new(pt.x(), pt.y()));
+            }
+        }
+        best.1
+    }
+}
+
+impl Support for Circle {
+    fn support(&self, dir: &Vector2) -> Point2 {
+        let len = dir.l2_norm();
+        if len < EPSILON * EPSILON {
+            return self.center().clone();
+        }
+        self.center()
+            .move_along(&(dir.clone() * (self.radius() / len)))
+    }
+}
+
+// Minkowski 差上的点 w = a - b, 同时记下 a, b 以求见证点
+#[derive(Clone)]
+struct Vertex {
+    w: Vector2,
+    a: Point2,
+    b: Point2,
+}
+
+fn support_vertex<A, B>(a: &A, b: &B, dir: &Vector2) -> Vertex
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    let pa = a.support(dir);
+    let pb = b.support(&-dir.clone());
+    Vertex {
+        w: pa.from(&pb),
+        a: pa,
+        b: pb,
+    }
+}
+
+fn lerp(p: &Point2, q: &Point2, t: f64) -> Point2 {
+    Point2::new(p.x() + (q.x() - p.x()) * t, p.y() + (q.y() - p.y()) * t)
+}
+
+// 线段 p -> q 上离原点最近的点的参数
+fn closest_param(p: &Vector2, q: &Vector2) -> f64 {
+    let d = q - p;
+    let len2 = d.square_l2_norm();
+    if len2 < EPSILON * EPSILON {
+        return 0.;
+    }
+    (-p.dot(&d) / len2).clamp(0., 1.)
+}
+
+enum Gjk {
+    Separated(Proximity),
+    // 包含原点的单纯形, 作为 EPA 的初始值
+    Overlap(Vec<Vertex>),
+}
+
+// 单纯形上离原点最近的点, 并把单纯形缩减为该点所在的面
+// 原点在三角形内时返回 None
+fn reduce(simplex: &mut Vec<Vertex>) -> Option<(Vector2, Point2, Point2)> {
+    if simplex.len() == 3 {
+        let (p, q, r) = (&simplex[0].w, &simplex[1].w, &simplex[2].w);
+        let area = (q - p).exterior(&(r - p));
+        let sides = [q.exterior(r), r.exterior(p), p.exterior(q)];
+        if area.abs() > EPSILON * EPSILON && sides.iter().all(|s| s * area >= 0.) {
+            return None;
+        }
+        // 原点在三角形外, 取最近的边
+        let best = (0..3)
+            .map(|k| {
+                let (i, j) = (k, (k + 1) % 3);
+                let t = closest_param(&simplex[i].w, &simplex[j].w);
+                let v = &simplex[i].w + &((&simplex[j].w - &simplex[i].w) * t);
+                (v.square_l2_norm(), i, j)
+            })
+            .min_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(Ordering::Equal))
+            .unwrap();
+        *simplex = vec![simplex[best.1].clone(), simplex[best.2].clone()];
+    }
+    if simplex.len() == 2 {
+        let t = closest_param(&simplex[0].w, &simplex[1].w);
+        if t <= 0. {
+            simplex.truncate(1);
+        } else if t >= 1. {
+            simplex.remove(0);
+        } else {
+            let (p, q) = (&simplex[0], &simplex[1]);
+            return Some((
+                &p.w + &((&q.w - &p.w) * t),
+                lerp(&p.a, &q.a, t),
+                lerp(&p.b, &q.b, t),
+            ));
+        }
+    }
+    let p = &simplex[0];
+    Some((p.w.clone(), p.a.clone(), p.b.clone()))
+}
+
+fn gjk<A, B>(a: &A, b: &B) -> Gjk
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    let mut simplex = vec![support_vertex(a, b, &Vector2::new(1., 0.))];
+    for _ in 0..64 {
+        let (v, on_a, on_b) = match reduce(&mut simplex) {
+            Some(res) => res,
+            None => return Gjk::Overlap(simplex),
+        };
+        let dist2 = v.square_l2_norm();
+        if dist2 < EPSILON * EPSILON {
+            return Gjk::Overlap(simplex);
+        }
+        let w = support_vertex(a, b, &-v.clone());
+        // 没有更靠近原点的支撑点, 已收敛
+        let done = dist2 - v.dot(&w.w) <= EPSILON * EPSILON * dist2.max(1.)
+            || simplex
+                .iter()
+                .any(|s| (&s.w - &w.w).square_l2_norm() < EPSILON * EPSILON);
+        if done {
+            let distance = dist2.sqrt();
+            return Gjk::Separated(Proximity {
+                distance,
+                normal: -v / distance,
+                on_a,
+                on_b,
+            });
+        }
+        simplex.push(w);
+    }
+    // 迭代次数用完时取当前的最近点
+    let (v, on_a, on_b) = match reduce(&mut simplex) {
+        Some(res) => res,
+        None => return Gjk::Overlap(simplex),
+    };
+    let distance = v.l2_norm();
+    if distance < EPSILON {
+        return Gjk::Overlap(simplex);
+    }
+    Gjk::Separated(Proximity {
+        normal: -v / distance,
+        distance,
+        on_a,
+        on_b,
+    })
+}
+
+// 把 GJK 结束时的单纯形扩充为三角形; Minkowski 差退化为线段或点时原点在其边界上, 深度为 0
+fn grow<A, B>(a: &A, b: &B, simplex: &mut Vec<Vertex>) -> Option<Vector2>
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    let axes = [Vector2::new(1., 0.), Vector2::new(0., 1.)];
+    if simplex.len() == 1 {
+        let found = axes.iter().find_map(|axis| {
+            [axis.clone(), -axis.clone()].iter().find_map(|dir| {
+                let w = support_vertex(a, b, dir);
+                if (&w.w - &simplex[0].w).l2_norm() > EPSILON {
+                    Some(w)
+                } else {
+                    None
+                }
+            })
+        });
+        match found {
+            Some(w) => simplex.push(w),
+            None => return Some(axes[0].clone()),
+        }
+    }
+    if simplex.len() == 2 {
+        let n = (&simplex[1].w - &simplex[0].w).normal_vector();
+        let found = [n.clone(), -n.clone()].iter().find_map(|dir| {
+            let w = support_vertex(a, b, dir);
+            if dir.dot(&(&w.w - &simplex[0].w)) > EPSILON {
+                Some(w)
+            } else {
+                None
+            }
+        });
+        match found {
+            Some(w) => simplex.push(w),
+            None => return Some(n),
+        }
+    }
+    None
+}
+
+fn epa<A, B>(a: &A, b: &B, mut polytope: Vec<Vertex>) -> Proximity
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    if let Some(normal) = grow(a, b, &mut polytope) {
+        let p = &polytope[0];
+        return Proximity {
+            distance: 0.,
+            normal,
+            on_a: p.a.clone(),
+            on_b: p.b.clone(),
+        };
+    }
+    // 保持逆时针
+    if (&polytope[1].w - &polytope[0].w).exterior(&(&polytope[2].w - &polytope[0].w)) < 0. {
+        polytope.swap(1, 2);
+    }
+    let mut best = (0, 0., Vector2::new(1., 0.));
+    for _ in 0..64 {
+        // 离原点最近的边及其外法向, 跳过退化的短边
+        let n = polytope.len();
+        let closest = (0..n)
+            .filter_map(|i| {
+                let (p, q) = (&polytope[i].w, &polytope[(i + 1) % n].w);
+                let edge = q - p;
+                if edge.l2_norm() < EPSILON {
+                    return None;
+                }
+                let normal = -edge.normal_vector();
+                Some((i, normal.dot(p), normal))
+            })
+            .min_by(|x, y| x.1.partial_cmp(&y.1).unwrap_or(Ordering::Equal));
+        best = match closest {
+            Some(closest) => closest,
+            None => break,
+        };
+        let w = support_vertex(a, b, &best.2);
+        if best.2.dot(&w.w) - best.1 <= EPSILON * best.1.max(1.) {
+            break;
+        }
+        polytope.insert(best.0 + 1, w);
+    }
+    let n = polytope.len();
+    let (p, q) = (&polytope[best.0], &polytope[(best.0 + 1) % n]);
+    let t = closest_param(&p.w, &q.w);
+    Proximity {
+        distance: best.1.max(0.),
+        normal: best.2,
+        on_a: lerp(&p.a, &q.a, t),
+        on_b: lerp(&p.b, &q.b, t),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Rotated, Support, Translated};
+    use crate::base::{Circle, Point2, Vector2};
+    use crate::geometric::{ConvexPolygon, HullOptions, PointsConvexHull};
+    use geo::LineString;
+
+    fn square(x: f64, y: f64, size: f64) -> ConvexPolygon {
+        ConvexPolygon::new(LineString::from(vec![
+            (x, y),
+            (x + size, y),
+            (x + size, y + size),
+            (x, y + size),
+        ]))
+    }
+
+    #[test]
+    fn test_gjk_distance() {
+        let a = square(0., 0., 1.);
+        let b = square(3., 0.5, 1.);
+        let res = a.distance_to(&b).unwrap();
+        assert!((res.distance - 2.).abs() < 1e-9);
+        assert_eq!(res.normal, Vector2::new(1., 0.));
+        assert!((res.on_a.x() - 1.).abs() < 1e-9 && (res.on_b.x() - 3.).abs() < 1e-9);
+        assert!((res.on_a.y() - res.on_b.y()).abs() < 1e-9);
+        assert!(a.penetration(&b).is_none());
+
+        // 旋转 45 度后角朝向 a, 间隙变小
+        let center = Point2::new(3.5, 1.);
+        let rotated = Rotated::new(&b, center, std::f64::consts::FRAC_PI_4);
+        let res = a.distance_to(&rotated).unwrap();
+        assert!((res.distance - (2.5 - std::f64::consts::FRAC_1_SQRT_2)).abs() < 1e-9);
+        assert!(res.on_a.euclid_distance(&Point2::new(1., 1.)) < 1e-9);
+
+        // 点集与圆
+        let pts = [
+            Point2::new(0., 0.),
+            Point2::new(2., 0.),
+            Point2::new(1., 1.),
+        ];
+        let circle = Circle::new(Point2::new(1., 4.), 1.);
+        let res = pts[..].distance_to(&circle).unwrap();
+        assert!((res.distance - 2.).abs() < 1e-9);
+        assert_eq!(res.on_a, Point2::new(1., 1.));
+        // 圆是曲边, 见证点只能逼近到切点附近
+        assert!(res.on_b.euclid_distance(&Point2::new(1., 3.)) < 1e-5);
+        let moved = Translated::new(&circle, Vector2::new(0., -2.5));
+        assert!(pts[..].distance_to(&moved).is_none());
+    }
+
+    #[test]
+    fn test_epa_penetration() {
+        let a = square(0., 0., 2.);
+        let b = square(1.5, 0.5, 2.);
+        let res = a.penetration(&b).unwrap();
+        assert!((res.distance - 0.5).abs() < 1e-9);
+        assert!((res.normal.x() - 1.).abs() < 1e-9);
+        assert!((res.on_a.x() - 2.).abs() < 1e-9 && (res.on_b.x() - 1.5).abs() < 1e-9);
+
+        // 与 SAT 的结果一致
+        let mut seed: u64 = 11;
+        let mut rand = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 33) as f64 / (1u64 << 31) as f64 * 4.
+        };
+        for _ in 0..50 {
+            let pts: Vec<Point2> = (0..8).map(|_| Point2::new(rand(), rand())).collect();
+            let others: Vec<Point2> = (0..8).map(|_| Point2::new(rand() + 2., rand())).collect();
+            let p = pts.convex_hull_with(&HullOptions::default()).polygon;
+            let q = others.convex_hull().polygon;
+            match (p.collide(&q), p.penetration(&q), p.distance_to(&q)) {
+                (Some(contact), Some(res), None) => {
+                    assert!((contact.depth - res.distance).abs() < 1e-6);
+                    let diff = res.on_a.from(&res.on_b);
+                    assert!((diff.l2_norm() - res.distance).abs() < 1e-6);
+                }
+                (None, None, Some(res)) => {
+                    let diff = res.on_b.from(&res.on_a);
+                    assert!((diff.l2_norm() - res.distance).abs() < 1e-6);
+                    assert!(res.distance > 0.);
+                }
+                _ => panic!("GJK and SAT disagree"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_gjk_degenerate() {
+        // 完全重合
+        let a = square(0., 0., 1.);
+        assert!(a.distance_to(&a).is_none());
+        let res = a.penetration(&a).unwrap();
+        assert!((res.distance - 1.).abs() < 1e-9);
+        assert!(res.distance.is_finite() && res.normal.l2_norm().is_finite());
+
+        // 边接触
+        let b = square(1., 0., 1.);
+        assert!(a.distance_to(&b).is_none());
+        let res = a.penetration(&b).unwrap();
+        assert!(res.distance.abs() < 1e-9);
+        assert!((res.normal.l2_norm() - 1.).abs() < 1e-9);
+
+        // 重合的单点
+        let pt = [Point2::new(1., 2.)];
+        assert!(pt[..].distance_to(&pt[..]).is_none());
+        let res = pt[..].penetration(&pt[..]).unwrap();
+        assert!(res.distance.abs() < 1e-9);
+        assert!(res.normal.l2_norm().is_finite());
+    }
+}
@@ -0,0 +1,261 @@
+use super::utils::to_ring;
+use super::{BooleanOps, GeneralPolygon, SimplePolygon, SinglePolygon, Triangulate, Visibility};
+use crate::base::{Point2, EPSILON};
+use std::collections::{HashMap, VecDeque};
+
+// 守卫覆盖情况
+#[derive(PartialEq, Clone, Debug)]
+pub struct Coverage {
+    // 各守卫可见区域的并
+    pub visible: GeneralPolygon,
+    // 没有被任何守卫看到的部分
+    pub uncovered: GeneralPolygon,
+    pub uncovered_area: f64,
+}
+
+impl Coverage {
+    // 未覆盖面积相对总面积可以忽略时视为完全覆盖, 布尔运算会留下细小的碎片
+    pub fn is_complete(&self) -> bool {
+        self.uncovered_area <= coverage_tolerance(self.visible.area() + self.uncovered_area)
+    }
+}
+
+fn coverage_tolerance(area: f64) -> f64 {
+    EPSILON * area.max(1.)
+}
+
+// 美术馆问题: 放置守卫, 使多边形内每个点都能被至少一个守卫看到
+pub trait ArtGallery {
+    // 三角剖分后对顶点 3 染色, 取最小的颜色类, 至多 ⌊n/3⌋ 个守卫 (Fisk)
+    fn guards(&self) -> Vec<Point2>;
+
+    // 贪心: 每次选能看到最多未覆盖面积的顶点, 再去掉多余的守卫
+    // 每轮对每个候选顶点做一次差运算, 去掉守卫时再各算一次覆盖, 共 O(n^2) 次布尔运算
+    // 数值误差导致无法继续覆盖时补上 guards() 的守卫再去冗余
+    // 结果不多于 guards(), 但不保证最少
+    fn guards_greedy(&self) -> Vec<Point2>;
+
+    fn coverage(&self, guards: &[Point2]) -> Coverage;
+}
+
+impl ArtGallery for SimplePolygon {
+    fn guards(&self) -> Vec<Point2> {
+        let pts: Vec<Point2> = self
+            .vertices()
+            .iter()
+            .map(|pt| Point2::new(pt.x(), pt.y()))
+            .collect();
+        let colors = three_coloring(&self.triangulate(), pts.len());
+        let mut classes = vec![vec![]; 3];
+        for (i, color) in colors.iter().enumerate() {
+            if let Some(color) = color {
+                classes[*color].push(pts[i].clone());
+            }
+        }
+        classes
+            .into_iter()
+            .filter(|class| !class.is_empty())
+            .min_by_key(|class| class.len())
+            .unwrap_or_default()
+    }
+
+    fn guards_greedy(&self) -> Vec<Point2> {
+        let fisk = self.guards();
+        let candidates: Vec<(Point2, SinglePolygon)> = to_ring(self._base_polygon.exterior())
+            .iter()
+            .map(|pt| Point2::new(pt.x(), pt.y()))
+            .filter_map(|pt| {
+                let region = self.visibility(&pt)?;
+                Some((pt, SinglePolygon::from(region)))
+            })
+            .collect();
+        let tolerance = coverage_tolerance(self.area());
+
+        let mut uncovered = GeneralPolygon::from(SinglePolygon::from(self.clone()));
+        let mut chosen: Vec<usize> = vec![];
+        while uncovered.area() > tolerance {
+            let rest = uncovered.area();
+            // 保留差运算的结果, 选中后不必重算
+            let best = candidates
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !chosen.contains(i))
+                .map(|(i, (_, region))| {
+                    let left = uncovered.difference(region);
+                    (i, rest - left.area(), left)
+                })
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+            match best {
+                Some((i, gain, left)) if gain > tolerance => {
+                    uncovered = left;
+                    chosen.push(i);
+                }
+                // 数值误差导致无法继续覆盖, 补上 3 染色的守卫, 多余的在下面去掉
+                _ => {
+                    for pt in fisk.iter() {
+                        match candidates.iter().position(|(c, _)| c == pt) {
+                            Some(i) if !chosen.contains(&i) => chosen.push(i),
+                            Some(_) => {}
+                            None => return fisk,
+                        }
+                    }
+                    break;
+                }
+            }
+        }
+
+        // 后选的守卫看到的新区域少, 从后往前尝试去掉
+        let mut k = chosen.len();
+        while k > 0 {
+            k -= 1;
+            let rest: Vec<Point2> = chosen
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != k)
+                .map(|(_, &i)| candidates[i].0.clone())
+                .collect();
+            if self.coverage(&rest).is_complete() {
+                chosen.remove(k);
+            }
+        }
+        if chosen.len() > fisk.len() {
+            return fisk;
+        }
+        chosen
+            .into_iter()
+            .map(|i| candidates[i].0.clone())
+            .collect()
+    }
+
+    fn coverage(&self, guards: &[Point2]) -> Coverage {
+        let visible = guards
+            .iter()
+            .filter_map(|pt| self.visibility(pt))
+            .fold(GeneralPolygon::default(), |res, region| {
+                res.union(&SinglePolygon::from(region))
+            });
+        let uncovered =
+            GeneralPolygon::from(SinglePolygon::from(self.clone())).difference(&visible);
+        let uncovered_area = uncovered.area();
+        Coverage {
+            visible,
+            uncovered,
+            uncovered_area,
+        }
+    }
+}
+
+// 简单多边形三角剖分的对偶图是树, 从一个三角形出发沿公共边传播颜色即可
+// 没有被三角形引用的顶点 (重复点或共线点) 不染色
+fn three_coloring(triangles: &[[usize; 3]], n: usize) -> Vec<Option<usize>> {
+    let mut colors = vec![None; n];
+    if triangles.is_empty() {
+        return colors;
+    }
+    let key = |a: usize, b: usize| (a.min(b), a.max(b));
+    let mut edges: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (t, tri) in triangles.iter().enumerate() {
+        for k in 0..3 {
+            edges
+                .entry(key(tri[k], tri[(k + 1) % 3]))
+                .or_default()
+                .push(t);
+        }
+    }
+
+    let mut visited = vec![false; triangles.len()];
+    let mut queue = VecDeque::new();
+    for (k, &v) in triangles[0].iter().enumerate() {
+        colors[v] = Some(k);
+    }
+    visited[0] = true;
+    queue.push_back(0);
+    while let Some(t) = queue.pop_front() {
+        let tri = triangles[t];
+        for k in 0..3 {
+            let (a, b) = (tri[k], tri[(k + 1) % 3]);
+            for &s in edges[&key(a, b)].iter() {
+                if visited[s] {
+                    continue;
+                }
+                visited[s] = true;
+                // 第三个顶点取剩下的颜色
+                let (ca, cb) = (colors[a].unwrap_or(0), colors[b].unwrap_or(1));
+                for &v in triangles[s].iter() {
+                    if v != a && v != b && colors[v].is_none() {
+                        colors[v] = Some(3 - ca - cb);
+                    }
+                }
+                queue.push_back(s);
+            }
+        }
+    }
+    colors
+}
+
+#[cfg(test)]
+mod test {
+    use super::ArtGallery;
+    use crate::base::Point2;
+    use crate::geometric::{FromPoint, SimplePolygon};
+
+    #[test]
+    fn test_guards() {
+        // 梳子形
+        let comb = SimplePolygon::from_points(vec![
+            (0.0, 0.0),
+            (9.0, 0.0),
+            (9.0, 5.0),
+            (8.0, 5.0),
+            (7.0, 1.0),
+            (6.0, 5.0),
+            (5.0, 1.0),
+            (4.0, 5.0),
+            (3.0, 1.0),
+            (2.0, 5.0),
+            (1.0, 1.0),
+            (0.0, 5.0),
+        ]);
+        let n = comb.vertices().len() - 1;
+        let guards = comb.guards();
+        assert!(!guards.is_empty() && guards.len() <= n / 3);
+        assert!(comb.coverage(&guards).is_complete());
+
+        let greedy = comb.guards_greedy();
+        assert!(greedy.len() <= guards.len());
+        assert!(comb.coverage(&greedy).is_complete());
+
+        // 三个房间, 贪心比 3 染色少用守卫
+        let rooms = SimplePolygon::from_points(vec![
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 3.0),
+            (6.0, 3.0),
+            (6.0, 4.0),
+            (10.0, 4.0),
+            (10.0, 10.0),
+            (7.0, 10.0),
+            (7.0, 6.0),
+            (5.0, 6.0),
+            (5.0, 10.0),
+            (0.0, 10.0),
+            (0.0, 6.0),
+            (3.0, 6.0),
+            (3.0, 4.0),
+            (0.0, 4.0),
+        ]);
+        let greedy = rooms.guards_greedy();
+        assert!(greedy.len() < rooms.guards().len());
+        assert!(rooms.coverage(&greedy).is_complete());
+
+        // 凸多边形一个守卫就够
+        let square =
+            SimplePolygon::from_points(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+        assert_eq!(square.guards_greedy().len(), 1);
+
+        let res = comb.coverage(&[Point2::new(0., 0.)]);
+        assert!(!res.is_complete());
+        assert!(res.uncovered_area > 1.);
+        assert!((res.visible.area() + res.uncovered_area - comb.area()).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,248 @@
+use super::utils::{cross, to_ring};
+use super::{ConvexPolygon, GeneralPolygon, SimplePolygon, SinglePolygon};
+use crate::base::{Point2, EPSILON};
+use geo::{LineString, Point};
+
+// 边的编号依次为外环的边, 再接各个洞的边
+// 第 i 条边连接环上的第 i 个和第 i + 1 个顶点
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Location {
+    Inside,
+    Outside,
+    OnBoundary { edge: usize },
+}
+
+// 边界到点的距离不超过 EPSILON 时视为在边界上
+pub trait Locate {
+    fn locate(&self, pt: &Point2) -> Location;
+
+    // 含边界
+    fn contains(&self, pt: &Point2) -> bool {
+        self.locate(pt) != Location::Outside
+    }
+}
+
+impl Locate for SimplePolygon {
+    fn locate(&self, pt: &Point2) -> Location {
+        locate_in_rings(
+            std::iter::once(self._base_polygon.exterior()),
+            &Point::new(pt.x(), pt.y()),
+        )
+    }
+}
+
+impl Locate for SinglePolygon {
+    fn locate(&self, pt: &Point2) -> Location {
+        locate_in_rings(
+            std::iter::once(self._base_polygon.exterior())
+                .chain(self._base_polygon.interiors().iter()),
+            &Point::new(pt.x(), pt.y()),
+        )
+    }
+}
+
+impl Locate for GeneralPolygon {
+    fn locate(&self, pt: &Point2) -> Location {
+        let mut offset = 0;
+        for pol in self.polygons.iter() {
+            match pol.locate(pt) {
+                Location::Outside => {}
+                Location::OnBoundary { edge } => {
+                    return Location::OnBoundary {
+                        edge: offset + edge,
+                    }
+                }
+                Location::Inside => return Location::Inside,
+            }
+            offset += std::iter::once(pol._base_polygon.exterior())
+                .chain(pol._base_polygon.interiors().iter())
+                .map(|line| to_ring(line).len())
+                .sum::<usize>();
+        }
+        Location::Outside
+    }
+}
+
+impl Locate for ConvexPolygon {
+    // 以第 0 个顶点为中心二分查找所在的扇形, O(log n)
+    fn locate(&self, pt: &Point2) -> Location {
+        let line = self._base_polygon.exterior();
+        let mut n = line.0.len();
+        if n > 1 && line.0[0] == line.0[n - 1] {
+            n -= 1;
+        }
+        let q = Point::new(pt.x(), pt.y());
+        let raw = |i: usize| Point::from(line.0[i]);
+        let turn = (2..n)
+            .map(|k| cross(&raw(0), &raw(1), &raw(k)))
+            .find(|c| c.abs() > EPSILON);
+        let ccw = match turn {
+            Some(c) => c > 0.,
+            // 退化为线段或点
+            None => return locate_in_rings(std::iter::once(line), &q),
+        };
+        // 逆时针顺序下的第 k 个顶点, 及其第 k 条边对应的原编号
+        let p = |k: usize| if ccw { raw(k) } else { raw((n - k) % n) };
+        let edge = |k: usize| if ccw { k } else { (2 * n - 1 - k) % n };
+
+        let p0 = p(0);
+        let d_first = signed_distance(&p0, &p(1), &q);
+        let d_last = signed_distance(&p(n - 1), &p0, &q);
+        if d_first < -EPSILON || d_last < -EPSILON {
+            return Location::Outside;
+        }
+        if d_first <= EPSILON && on_segment(&p0, &p(1), &q) {
+            return Location::OnBoundary { edge: edge(0) };
+        }
+        if d_last <= EPSILON && on_segment(&p(n - 1), &p0, &q) {
+            return Location::OnBoundary { edge: edge(n - 1) };
+        }
+        if d_first <= EPSILON || d_last <= EPSILON {
+            return Location::Outside;
+        }
+
+        let (mut lo, mut hi) = (1, n - 1);
+        while hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            if cross(&p0, &p(mid), &q) >= 0. {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let d = signed_distance(&p(lo), &p(lo + 1), &q);
+        if d < -EPSILON {
+            Location::Outside
+        } else if d <= EPSILON {
+            Location::OnBoundary { edge: edge(lo) }
+        } else {
+            Location::Inside
+        }
+    }
+}
+
+// pt 到直线 ab 的有向距离, 左侧为正
+fn signed_distance(a: &Point<f64>, b: &Point<f64>, pt: &Point<f64>) -> f64 {
+    let len = (b.x() - a.x()).hypot(b.y() - a.y());
+    if len < EPSILON {
+        return (pt.x() - a.x()).hypot(pt.y() - a.y());
+    }
+    cross(a, b, pt) / len
+}
+
+// pt 到线段 ab 的距离不超过 EPSILON
+fn on_segment(a: &Point<f64>, b: &Point<f64>, pt: &Point<f64>) -> bool {
+    let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 < EPSILON * EPSILON {
+        0.
+    } else {
+        (((pt.x() - a.x()) * dx + (pt.y() - a.y()) * dy) / len2).clamp(0., 1.)
+    };
+    (a.x() + t * dx - pt.x()).hypot(a.y() + t * dy - pt.y()) <= EPSILON
+}
+
+// 环绕数, 不含边界
+fn winding_number(ring: &[Point<f64>], pt: &Point<f64>) -> i32 {
+    let n = ring.len();
+    let mut wn = 0;
+    for i in 0..n {
+        let (a, b) = (&ring[i], &ring[(i + 1) % n]);
+        if a.y() <= pt.y() {
+            if b.y() > pt.y() && cross(a, b, pt) > 0. {
+                wn += 1;
+            }
+        } else if b.y() <= pt.y() && cross(a, b, pt) < 0. {
+            wn -= 1;
+        }
+    }
+    wn
+}
+
+// 第一个环为外环, 其余为洞
+fn locate_in_rings<'a, I>(lines: I, pt: &Point<f64>) -> Location
+where
+    I: Iterator<Item = &'a LineString<f64>>,
+{
+    let mut offset = 0;
+    let mut inside = false;
+    for (k, line) in lines.enumerate() {
+        let ring = to_ring(line);
+        let n = ring.len();
+        if let Some(i) = (0..n).find(|&i| on_segment(&ring[i], &ring[(i + 1) % n], pt)) {
+            return Location::OnBoundary { edge: offset + i };
+        }
+        let wn = winding_number(&ring, pt);
+        if k == 0 {
+            inside = wn != 0;
+        } else if wn != 0 {
+            inside = false;
+        }
+        offset += n;
+    }
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Locate, Location};
+    use crate::base::Point2;
+    use crate::geometric::{ConvexPolygon, FromPoint, FromPoints, SimplePolygon, SinglePolygon};
+    use geo::LineString;
+    use std::mem::discriminant;
+
+    #[test]
+    fn test_locate() {
+        let pol = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![vec![(4.0, 4.0), (6.0, 4.0), (6.0, 6.0), (4.0, 6.0)]],
+        );
+        assert_eq!(pol.locate(&Point2::new(1., 1.)), Location::Inside);
+        assert_eq!(pol.locate(&Point2::new(5., 5.)), Location::Outside);
+        assert_eq!(pol.locate(&Point2::new(11., 5.)), Location::Outside);
+        assert_eq!(
+            pol.locate(&Point2::new(10., 5.)),
+            Location::OnBoundary { edge: 1 }
+        );
+        assert_eq!(
+            pol.locate(&Point2::new(5., 6.)),
+            Location::OnBoundary { edge: 6 }
+        );
+        assert!(!pol.contains(&Point2::new(5., 5.)));
+
+        let simple = SimplePolygon::from_points(vec![
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 4.0),
+            (0.0, 4.0),
+        ]);
+        assert!(simple.contains(&Point2::new(0.5, 3.)));
+        assert!(!simple.contains(&Point2::new(2., 2.)));
+    }
+
+    #[test]
+    fn test_locate_convex() {
+        // 顺时针存储
+        let pts = vec![(0.0, 0.0), (0.0, 2.0), (1.0, 3.0), (3.0, 2.0), (3.0, 0.0)];
+        let convex = ConvexPolygon::new(LineString::from(pts.clone()));
+        let simple = SimplePolygon::from_points(pts);
+        for i in -2..10 {
+            for j in -2..10 {
+                let pt = Point2::new(i as f64 * 0.5, j as f64 * 0.5);
+                // 顶点处两条边都可能被返回, 只比较类别
+                assert_eq!(
+                    discriminant(&convex.locate(&pt)),
+                    discriminant(&simple.locate(&pt)),
+                    "{}",
+                    pt
+                );
+            }
+        }
+    }
+}
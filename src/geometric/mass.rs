@@ -0,0 +1,233 @@
+use super::utils::oriented_rings;
+use super::{ConvexPolygon, SimplePolygon, SinglePolygon};
+use crate::base::{Point2, Vector2, EPSILON};
+use geo::Polygon;
+
+// 二阶矩 (面积惯性矩), ixx = ∫ y² dA, iyy = ∫ x² dA, ixy = ∫ xy dA
+#[derive(PartialEq, Clone, Debug)]
+pub struct Inertia {
+    pub ixx: f64,
+    pub iyy: f64,
+    pub ixy: f64,
+}
+
+impl Inertia {
+    // 极惯性矩
+    pub fn polar(&self) -> f64 {
+        self.ixx + self.iyy
+    }
+
+    // 主惯性矩及对应的主轴方向 (单位向量), 大的在前
+    // 绕方向 d 的轴的惯性矩为 dᵀ [[ixx, -ixy], [-ixy, iyy]] d
+    pub fn principal(&self) -> [(f64, Vector2); 2] {
+        let mean = (self.ixx + self.iyy) / 2.;
+        let radius = ((self.ixx - self.iyy) / 2.).hypot(self.ixy);
+        let theta = (-2. * self.ixy).atan2(self.ixx - self.iyy) / 2.;
+        let (sin, cos) = theta.sin_cos();
+        [
+            (mean + radius, Vector2::new(cos, sin)),
+            (mean - radius, Vector2::new(-sin, cos)),
+        ]
+    }
+
+    // 平移 (dx, dy) 后的坐标系中的二阶矩, area 为面积, (cx, cy) 为原坐标系中的形心
+    fn shift(&self, area: f64, cx: f64, cy: f64, dx: f64, dy: f64) -> Inertia {
+        // 平行轴定理: 先移到形心, 再移到新原点
+        let (nx, ny) = (cx - dx, cy - dy);
+        Inertia {
+            ixx: self.ixx - area * cy * cy + area * ny * ny,
+            iyy: self.iyy - area * cx * cx + area * nx * nx,
+            ixy: self.ixy - area * cx * cy + area * nx * ny,
+        }
+    }
+}
+
+// 截面的二阶矩, 分别关于形心和坐标原点
+#[derive(PartialEq, Clone, Debug)]
+pub struct SecondMoments {
+    pub centroidal: Inertia,
+    pub origin: Inertia,
+}
+
+// 截面几何性质, 洞的部分扣除; 面积为 0 时返回 None
+pub trait MassProperties {
+    fn centroid(&self) -> Option<Point2>;
+
+    fn second_moments(&self) -> Option<SecondMoments>;
+
+    // 关于形心的主惯性矩和主轴, 大的在前
+    fn principal_axes(&self) -> Option<[(f64, Vector2); 2]> {
+        Some(self.second_moments()?.centroidal.principal())
+    }
+
+    // 关于形心的极惯性矩
+    fn polar_moment(&self) -> Option<f64> {
+        Some(self.second_moments()?.centroidal.polar())
+    }
+
+    // 关于形心的回转半径 (kx, ky), k = sqrt(I / A)
+    fn radius_of_gyration(&self) -> Option<(f64, f64)>;
+}
+
+struct Integrals {
+    area: f64,
+    centroid: Point2,
+    moments: SecondMoments,
+}
+
+// 格林公式逐边累加; 以第一个顶点为参考点, 避免远离原点时的抵消误差
+fn integrate(pol: &Polygon<f64>) -> Option<Integrals> {
+    let rings = oriented_rings(pol);
+    let first = rings.first()?.first()?;
+    let (rx, ry) = (first.x(), first.y());
+    let (mut area, mut sx, mut sy) = (0., 0., 0.);
+    let (mut ixx, mut iyy, mut ixy) = (0., 0., 0.);
+    for ring in rings.iter() {
+        let n = ring.len();
+        for i in 0..n {
+            let (x0, y0) = (ring[i].x() - rx, ring[i].y() - ry);
+            let (x1, y1) = (ring[(i + 1) % n].x() - rx, ring[(i + 1) % n].y() - ry);
+            let a = x0 * y1 - x1 * y0;
+            area += a;
+            sx += (x0 + x1) * a;
+            sy += (y0 + y1) * a;
+            ixx += (y0 * y0 + y0 * y1 + y1 * y1) * a;
+            iyy += (x0 * x0 + x0 * x1 + x1 * x1) * a;
+            ixy += (x0 * y1 + 2. * x0 * y0 + 2. * x1 * y1 + x1 * y0) * a;
+        }
+    }
+    let area = area / 2.;
+    if area.abs() < EPSILON * EPSILON {
+        return None;
+    }
+    let (cx, cy) = (sx / (6. * area), sy / (6. * area));
+    let local = Inertia {
+        ixx: ixx / 12.,
+        iyy: iyy / 12.,
+        ixy: ixy / 24.,
+    };
+    Some(Integrals {
+        area,
+        centroid: Point2::new(cx + rx, cy + ry),
+        moments: SecondMoments {
+            centroidal: local.shift(area, cx, cy, cx, cy),
+            origin: local.shift(area, cx, cy, -rx, -ry),
+        },
+    })
+}
+
+fn radius_of_gyration(pol: &Polygon<f64>) -> Option<(f64, f64)> {
+    let res = integrate(pol)?;
+    let inertia = &res.moments.centroidal;
+    Some((
+        (inertia.ixx / res.area).max(0.).sqrt(),
+        (inertia.iyy / res.area).max(0.).sqrt(),
+    ))
+}
+
+impl MassProperties for SimplePolygon {
+    fn centroid(&self) -> Option<Point2> {
+        Some(integrate(&self._base_polygon)?.centroid)
+    }
+
+    fn second_moments(&self) -> Option<SecondMoments> {
+        Some(integrate(&self._base_polygon)?.moments)
+    }
+
+    fn radius_of_gyration(&self) -> Option<(f64, f64)> {
+        radius_of_gyration(&self._base_polygon)
+    }
+}
+
+impl MassProperties for SinglePolygon {
+    fn centroid(&self) -> Option<Point2> {
+        Some(integrate(&self._base_polygon)?.centroid)
+    }
+
+    fn second_moments(&self) -> Option<SecondMoments> {
+        Some(integrate(&self._base_polygon)?.moments)
+    }
+
+    fn radius_of_gyration(&self) -> Option<(f64, f64)> {
+        radius_of_gyration(&self._base_polygon)
+    }
+}
+
+impl MassProperties for ConvexPolygon {
+    fn centroid(&self) -> Option<Point2> {
+        Some(integrate(&self._base_polygon)?.centroid)
+    }
+
+    fn second_moments(&self) -> Option<SecondMoments> {
+        Some(integrate(&self._base_polygon)?.moments)
+    }
+
+    fn radius_of_gyration(&self) -> Option<(f64, f64)> {
+        radius_of_gyration(&self._base_polygon)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MassProperties;
+    use crate::base::Point2;
+    use crate::geometric::{ConvexPolygon, FromPoint, FromPoints, SimplePolygon, SinglePolygon};
+    use geo::LineString;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_mass_properties() {
+        // 宽 4 高 2 的矩形, 左下角在 (1, 1), 顺时针存储
+        let rect = SimplePolygon::from_points(vec![(1.0, 1.0), (1.0, 3.0), (5.0, 3.0), (5.0, 1.0)]);
+        assert_eq!(rect.centroid().unwrap(), Point2::new(3., 2.));
+        let moments = rect.second_moments().unwrap();
+        // b h³ / 12, h b³ / 12
+        assert!(close(moments.centroidal.ixx, 4. * 8. / 12.));
+        assert!(close(moments.centroidal.iyy, 2. * 64. / 12.));
+        assert!(close(moments.centroidal.ixy, 0.));
+        // 平行轴定理
+        assert!(close(moments.origin.ixx, 8. / 3. + 8. * 4.));
+        assert!(close(moments.origin.iyy, 32. / 3. + 8. * 9.));
+        assert!(close(moments.origin.ixy, 8. * 6.));
+        let [(major, u), (minor, _)] = rect.principal_axes().unwrap();
+        assert!(close(major, 32. / 3.) && close(minor, 8. / 3.));
+        assert!(close(u.x().abs(), 0.) && close(u.y().abs(), 1.));
+        assert!(close(rect.polar_moment().unwrap(), 40. / 3.));
+        let (kx, ky) = rect.radius_of_gyration().unwrap();
+        assert!(close(kx, (1f64 / 3.).sqrt()) && close(ky, (4f64 / 3.).sqrt()));
+
+        // 空心方管, 洞的部分扣除
+        let tube = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)],
+            vec![vec![(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)]],
+        );
+        assert_eq!(tube.centroid().unwrap(), Point2::new(2., 2.));
+        let moments = tube.second_moments().unwrap();
+        assert!(close(moments.centroidal.ixx, (256. - 16.) / 12.));
+        assert!(close(moments.centroidal.ixx, moments.centroidal.iyy));
+
+        // 直角三角形, 关于形心的惯性积为 -b² h² / 72
+        let tri = ConvexPolygon::new(LineString::from(vec![(0.0, 0.0), (3.0, 0.0), (0.0, 6.0)]));
+        assert_eq!(tri.centroid().unwrap(), Point2::new(1., 2.));
+        let moments = tri.second_moments().unwrap();
+        assert!(close(moments.centroidal.ixx, 3. * 216. / 36.));
+        assert!(close(moments.centroidal.iyy, 6. * 27. / 36.));
+        assert!(close(moments.centroidal.ixy, -9. * 36. / 72.));
+        let axes = tri.principal_axes().unwrap();
+        // 主轴相互垂直, 主惯性矩之和不变
+        assert!(close(axes[0].1.dot(&axes[1].1), 0.));
+        assert!(close(axes[0].0 + axes[1].0, tri.polar_moment().unwrap()));
+        let inertia = &moments.centroidal;
+        for (value, dir) in axes.iter() {
+            let (c, s) = (dir.x(), dir.y());
+            let on_axis = c * c * inertia.ixx + s * s * inertia.iyy - 2. * c * s * inertia.ixy;
+            assert!(close(*value, on_axis));
+        }
+
+        let flat = SimplePolygon::from_points(vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+        assert_eq!(flat.centroid(), None);
+    }
+}
@@ -0,0 +1,205 @@
+use super::boolean::positive_fill;
+use super::utils::oriented_rings;
+use super::{ConvexDecomposition, ConvexPolygon, GeneralPolygon, SimplePolygon};
+use crate::base::point::is_ccw;
+use crate::base::{AsVector, Point2, EPSILON};
+use geo::{LineString, Point, Polygon};
+
+// Minkowski 和 A ⊕ B = {a + b}, 差 A ⊖ B = A ⊕ (-B)
+// 差的结果即 B 相对 A 的配置空间障碍物
+pub trait MinkowskiSum<Rhs = Self> {
+    type Output;
+
+    fn minkowski_sum(&self, other: &Rhs) -> Self::Output;
+
+    fn minkowski_difference(&self, other: &Rhs) -> Self::Output;
+}
+
+impl MinkowskiSum for ConvexPolygon {
+    type Output = ConvexPolygon;
+
+    // 按边的极角归并, O(n + m)
+    fn minkowski_sum(&self, other: &ConvexPolygon) -> ConvexPolygon {
+        let res = convex_sum(
+            &ccw_ring(&self._base_polygon),
+            &ccw_ring(&other._base_polygon),
+        );
+        ConvexPolygon::new(to_line_string(&res))
+    }
+
+    fn minkowski_difference(&self, other: &ConvexPolygon) -> ConvexPolygon {
+        let res = convex_sum(
+            &ccw_ring(&self._base_polygon),
+            &reflect(&ccw_ring(&other._base_polygon)),
+        );
+        ConvexPolygon::new(to_line_string(&res))
+    }
+}
+
+impl MinkowskiSum for SimplePolygon {
+    type Output = GeneralPolygon;
+
+    // 先凸分解, 两两求和后取并
+    fn minkowski_sum(&self, other: &SimplePolygon) -> GeneralPolygon {
+        pieces_sum(&convex_pieces(self), &convex_pieces(other))
+    }
+
+    fn minkowski_difference(&self, other: &SimplePolygon) -> GeneralPolygon {
+        let reflected: Vec<Vec<Point2>> = convex_pieces(other)
+            .iter()
+            .map(|piece| reflect(piece))
+            .collect();
+        pieces_sum(&convex_pieces(self), &reflected)
+    }
+}
+
+pub(crate) fn ccw_ring(pol: &Polygon<f64>) -> Vec<Point2> {
+    let mut ring = oriented_rings(pol).remove(0);
+    ring.dedup();
+    while ring.len() > 1 && ring[0] == ring[ring.len() - 1] {
+        ring.pop();
+    }
+    ring.iter().map(|pt| Point2::new(pt.x(), pt.y())).collect()
+}
+
+pub(crate) fn reflect(ring: &[Point2]) -> Vec<Point2> {
+    ring.iter()
+        .map(|pt| Point2::new(-pt.x(), -pt.y()))
+        .collect()
+}
+
+pub(crate) fn to_line_string(ring: &[Point2]) -> LineString<f64> {
+    LineString::from(
+        ring.iter()
+            .map(|pt| Point::new(pt.x(), pt.y()))
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn convex_pieces(pol: &SimplePolygon) -> Vec<Vec<Point2>> {
+    pol.convex_decomposition()
+        .iter()
+        .map(|piece| ccw_ring(&piece._base_polygon))
+        .filter(|ring| ring.len() >= 3)
+        .collect()
+}
+
+fn pieces_sum(a: &[Vec<Point2>], b: &[Vec<Point2>]) -> GeneralPolygon {
+    let mut rings = vec![];
+    for p in a.iter() {
+        for q in b.iter() {
+            let sum = convex_sum(p, q);
+            if sum.len() >= 3 {
+                rings.push(
+                    sum.iter()
+                        .map(|pt| Point::new(pt.x(), pt.y()))
+                        .collect::<Vec<_>>(),
+                );
+            }
+        }
+    }
+    // 各块均为逆时针, 环绕数为正的部分即并集
+    positive_fill(&rings)
+}
+
+// 最下 (其次最左) 的顶点
+pub(crate) fn lowest(ring: &[Point2]) -> usize {
+    (0..ring.len())
+        .min_by(|&i, &j| {
+            ring[i]
+                .y()
+                .total_cmp(&ring[j].y())
+                .then(ring[i].x().total_cmp(&ring[j].x()))
+        })
+        .unwrap_or(0)
+}
+
+// a, b 均为逆时针凸多边形
+fn convex_sum(a: &[Point2], b: &[Point2]) -> Vec<Point2> {
+    let (n, m) = (a.len(), b.len());
+    if n == 0 || m == 0 {
+        return vec![];
+    }
+    let (i0, j0) = (lowest(a), lowest(b));
+    let pa = |i: usize| &a[(i0 + i) % n];
+    let pb = |j: usize| &b[(j0 + j) % m];
+
+    let mut res: Vec<Point2> = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        res.push(pa(i).move_along(&pb(j).as_vector()));
+        let ea = pa(i + 1).from(pa(i));
+        let eb = pb(j + 1).from(pb(j));
+        let c = ea.exterior(&eb);
+        if j == m || (i < n && c > EPSILON) {
+            i += 1;
+        } else if i == n || c < -EPSILON {
+            j += 1;
+        } else {
+            i += 1;
+            j += 1;
+        }
+    }
+
+    // 去掉共线点
+    let mut k = 0;
+    while res.len() >= 3 && k < res.len() {
+        let len = res.len();
+        if is_ccw(&res[(k + len - 1) % len], &res[k], &res[(k + 1) % len]) {
+            k += 1;
+        } else {
+            res.remove(k);
+            k = k.saturating_sub(1);
+        }
+    }
+    if res.len() < 3 {
+        return vec![];
+    }
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::MinkowskiSum;
+    use crate::geometric::{ConvexPolygon, FromPoint, SimplePolygon};
+    use geo::LineString;
+
+    #[test]
+    fn test_convex_minkowski() {
+        let square = ConvexPolygon::new(LineString::from(vec![
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (1.0, 1.0),
+            (0.0, 1.0),
+        ]));
+        let triangle =
+            ConvexPolygon::new(LineString::from(vec![(0.0, 0.0), (2.0, 0.0), (0.0, 2.0)]));
+        let sum = square.minkowski_sum(&triangle);
+        // (0, 0), (3, 0), (3, 1), (1, 3), (0, 3)
+        assert_eq!(sum.vertices().len(), 6);
+        assert!((sum.area().abs() - 7.).abs() < 1e-9);
+
+        let diff = square.minkowski_difference(&square);
+        assert!((diff.area().abs() - 4.).abs() < 1e-9);
+        let xs: Vec<f64> = diff.vertices().iter().map(|pt| pt.x()).collect();
+        assert!(xs.iter().cloned().fold(f64::INFINITY, f64::min) == -1.);
+    }
+
+    #[test]
+    fn test_simple_minkowski() {
+        let l_shape = SimplePolygon::from_points(vec![
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 4.0),
+            (0.0, 4.0),
+        ]);
+        let square =
+            SimplePolygon::from_points(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        let sum = l_shape.minkowski_sum(&square);
+        assert_eq!(sum.len(), 1);
+        assert_eq!(sum.num_hole(), 0);
+        assert!((sum.area() - (25. - 9.)).abs() < 1e-9);
+    }
+}
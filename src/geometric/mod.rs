@@ -1,15 +1,61 @@
 pub(crate) mod utils;
+pub(crate) mod boolean;
+pub(crate) mod triangulate;
+pub(crate) mod decomposition;
+pub(crate) mod offset;
+pub(crate) mod minkowski;
+pub(crate) mod locate;
 pub(crate) mod from_points;
 pub(crate) mod convex_hull;
+pub(crate) mod calipers;
+pub(crate) mod collision;
+pub(crate) mod gjk;
+pub(crate) mod convex_intersection;
+pub(crate) mod clip;
+pub(crate) mod enclosing_circle;
+pub(crate) mod polylabel;
+pub(crate) mod mass;
+pub(crate) mod orientation;
+pub(crate) mod transform;
+pub(crate) mod simplify;
+pub(crate) mod skeleton;
+pub(crate) mod visibility;
+pub(crate) mod guards;
+pub(crate) mod deserialize;
+pub(crate) mod simplicity;
 pub mod draw;
+pub mod error;
 
 pub mod single_polygon;
 pub mod simple_polygon;
 pub mod convex_polygon;
+pub mod general_polygon;
+pub mod delaunay;
 
 pub use from_points::{FromPoints, FromPoint};
 pub use single_polygon::SinglePolygon;
 pub use simple_polygon::SimplePolygon;
 pub use convex_polygon::ConvexPolygon;
+pub use general_polygon::GeneralPolygon;
 pub use convex_hull::SimpleConvexHull as ConvexHull;
-pub use draw::Draw;
\ No newline at end of file
+pub use convex_hull::{Hull, HullMode, HullOptions, PointsConvexHull};
+pub use draw::Draw;
+pub use error::PolygonError;
+pub use boolean::{BooleanOp, BooleanOps};
+pub use triangulate::Triangulate;
+pub use decomposition::{ConvexDecomposition, DecompositionMode};
+pub use delaunay::{ConstrainedDelaunay, DelaunayOptions, Mesh};
+pub use offset::{JoinStyle, Offset, OffsetOptions};
+pub use minkowski::MinkowskiSum;
+pub use calipers::OrientedRect;
+pub use collision::Contact;
+pub use gjk::{Proximity, Rotated, Support, Translated};
+pub use clip::{Clip, Rect};
+pub use enclosing_circle::EnclosingCircle;
+pub use mass::{Inertia, MassProperties, SecondMoments};
+pub use orientation::{Orientation, Winding};
+pub use simplify::{Simplify, SimplifyMode, SimplifyOptions};
+pub use skeleton::{Skeleton, StraightSkeleton};
+pub use visibility::Visibility;
+pub use guards::{ArtGallery, Coverage};
+pub use locate::{Locate, Location};
\ No newline at end of file
@@ -0,0 +1,231 @@
+use super::boolean::positive_fill;
+use super::utils::oriented_rings;
+use super::{GeneralPolygon, SimplePolygon, SinglePolygon};
+use crate::base::{Vector2, EPSILON};
+use geo::Point;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum JoinStyle {
+    Miter,
+    Round,
+    Square,
+}
+
+// miter_limit 为尖角顶点到原顶点的最大距离与偏移距离之比, 超过时改为方角
+// arc_tolerance 为圆角折线与圆弧的最大偏差
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct OffsetOptions {
+    pub join: JoinStyle,
+    pub miter_limit: f64,
+    pub arc_tolerance: f64,
+}
+
+impl Default for OffsetOptions {
+    fn default() -> Self {
+        OffsetOptions {
+            join: JoinStyle::Miter,
+            miter_limit: 2.,
+            arc_tolerance: 0.1,
+        }
+    }
+}
+
+// 正距离向外扩张, 负距离向内收缩
+// 收缩超过宽度时区域会分裂为多块或消失, 洞随外环收缩而变大
+pub trait Offset {
+    fn offset(&self, distance: f64, options: &OffsetOptions) -> GeneralPolygon;
+
+    fn inflate(&self, distance: f64, options: &OffsetOptions) -> GeneralPolygon {
+        self.offset(distance.abs(), options)
+    }
+
+    fn deflate(&self, distance: f64, options: &OffsetOptions) -> GeneralPolygon {
+        self.offset(-distance.abs(), options)
+    }
+}
+
+impl Offset for SimplePolygon {
+    fn offset(&self, distance: f64, options: &OffsetOptions) -> GeneralPolygon {
+        offset_rings(&oriented_rings(&self._base_polygon), distance, options)
+    }
+}
+
+impl Offset for SinglePolygon {
+    fn offset(&self, distance: f64, options: &OffsetOptions) -> GeneralPolygon {
+        offset_rings(&self.oriented_rings(), distance, options)
+    }
+}
+
+impl Offset for GeneralPolygon {
+    fn offset(&self, distance: f64, options: &OffsetOptions) -> GeneralPolygon {
+        offset_rings(&self.oriented_rings(), distance, options)
+    }
+}
+
+// 每个环沿边的右侧 (区域外侧) 平移, 再按正环绕数规则去掉自交和反向的部分
+pub(crate) fn offset_rings(
+    rings: &[Vec<Point<f64>>],
+    distance: f64,
+    options: &OffsetOptions,
+) -> GeneralPolygon {
+    let raw: Vec<Vec<Point<f64>>> = rings
+        .iter()
+        .map(|ring| offset_ring(ring, distance, options))
+        .filter(|ring| ring.len() >= 3)
+        .collect();
+    positive_fill(&raw)
+}
+
+fn offset_ring(ring: &[Point<f64>], d: f64, options: &OffsetOptions) -> Vec<Point<f64>> {
+    let mut pts = ring.to_vec();
+    pts.dedup();
+    while pts.len() > 1 && pts[0] == pts[pts.len() - 1] {
+        pts.pop();
+    }
+    let n = pts.len();
+    if n < 3 || d.abs() < EPSILON {
+        return pts;
+    }
+
+    // 边的单位外法向量, 即逆时针法向量取反
+    let normals: Vec<Vector2> = (0..n)
+        .map(|i| {
+            let (a, b) = (&pts[i], &pts[(i + 1) % n]);
+            -Vector2::new(b.x() - a.x(), b.y() - a.y()).normal_vector()
+        })
+        .collect();
+
+    let tolerance = options.arc_tolerance.min(d.abs() * 0.01).max(EPSILON);
+    let steps_per_rad =
+        std::f64::consts::PI / (1. - tolerance / d.abs()).acos() / (2. * std::f64::consts::PI);
+    let miter_limit = options.miter_limit.max(1.);
+
+    let mut res = vec![];
+    let at = |c: &Point<f64>, v: &Vector2| Point::new(c.x() + v.x(), c.y() + v.y());
+    for j in 0..n {
+        let c = &pts[j];
+        let (n1, n2) = (&normals[(j + n - 1) % n], &normals[j]);
+        let sin_a = n1.exterior(n2).clamp(-1., 1.);
+        let cos_a = n1.dot(n2);
+        if cos_a > 0. && (sin_a * d).abs() < EPSILON {
+            // 近似共线
+            res.push(at(c, &(n1.clone() * d)));
+            continue;
+        }
+        if sin_a * d < 0. {
+            // 凹角处保留原顶点, 形成的小环由合并时去掉
+            res.push(at(c, &(n1.clone() * d)));
+            res.push(*c);
+            res.push(at(c, &(n2.clone() * d)));
+            continue;
+        }
+        match options.join {
+            JoinStyle::Miter if 1. + cos_a >= 2. / (miter_limit * miter_limit) => {
+                res.push(at(c, &((n1 + n2) * (d / (1. + cos_a)))));
+            }
+            JoinStyle::Round => {
+                let angle = sin_a.atan2(cos_a);
+                let steps = ((angle.abs() * steps_per_rad).ceil() as usize).max(1);
+                let (sin_s, cos_s) = (angle / steps as f64).sin_cos();
+                let mut v = n1.clone() * d;
+                res.push(at(c, &v));
+                for _ in 0..steps {
+                    v = Vector2::new(v.x() * cos_s - v.y() * sin_s, v.x() * sin_s + v.y() * cos_s);
+                    res.push(at(c, &v));
+                }
+            }
+            _ => {
+                let dx = (sin_a.atan2(cos_a) / 4.).tan();
+                res.push(at(
+                    c,
+                    &(Vector2::new(n1.x() - n1.y() * dx, n1.y() + n1.x() * dx) * d),
+                ));
+                res.push(at(
+                    c,
+                    &(Vector2::new(n2.x() + n2.y() * dx, n2.y() - n2.x() * dx) * d),
+                ));
+            }
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JoinStyle, Offset, OffsetOptions};
+    use crate::geometric::{FromPoint, FromPoints, SimplePolygon, SinglePolygon};
+
+    fn options(join: JoinStyle) -> OffsetOptions {
+        OffsetOptions {
+            join,
+            ..OffsetOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_inflate_joins() {
+        let square =
+            SimplePolygon::from_points(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+        let miter = square.inflate(1., &options(JoinStyle::Miter));
+        assert_eq!(miter.len(), 1);
+        assert!((miter.area() - 16.).abs() < 1e-9);
+
+        let round = square.inflate(1., &options(JoinStyle::Round));
+        assert!((round.area() - (12. + std::f64::consts::PI)).abs() < 0.05);
+
+        let bevel = square.inflate(1., &options(JoinStyle::Square));
+        assert!(bevel.area() > round.area() && bevel.area() < miter.area());
+
+        // 超过 miter_limit 时改为方角
+        let limited = square.inflate(
+            1.,
+            &OffsetOptions {
+                miter_limit: 1.,
+                ..OffsetOptions::default()
+            },
+        );
+        assert!((limited.area() - bevel.area()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_deflate() {
+        let square =
+            SimplePolygon::from_points(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+        let res = square.deflate(0.5, &OffsetOptions::default());
+        assert!((res.area() - 1.).abs() < 1e-9);
+        assert!(square.deflate(1.5, &OffsetOptions::default()).is_empty());
+
+        // 哑铃形收缩后断开
+        let dumbbell = SimplePolygon::from_points(vec![
+            (0.0, 0.0),
+            (3.0, 0.0),
+            (3.0, 1.0),
+            (4.0, 1.0),
+            (4.0, 0.0),
+            (7.0, 0.0),
+            (7.0, 3.0),
+            (4.0, 3.0),
+            (4.0, 2.0),
+            (3.0, 2.0),
+            (3.0, 3.0),
+            (0.0, 3.0),
+        ]);
+        let res = dumbbell.deflate(0.75, &OffsetOptions::default());
+        assert_eq!(res.len(), 2);
+        assert!((res.area() - 2. * 1.5 * 1.5).abs() < 1e-9);
+
+        // 外环收缩时洞变大
+        let pol = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![vec![(4.0, 4.0), (6.0, 4.0), (6.0, 6.0), (4.0, 6.0)]],
+        );
+        let res = pol.deflate(1., &OffsetOptions::default());
+        assert_eq!(res.len(), 1);
+        assert_eq!(res.num_hole(), 1);
+        assert!((res.area() - (64. - 16.)).abs() < 1e-9);
+        // 扩张时洞被填满
+        let res = pol.inflate(1.5, &OffsetOptions::default());
+        assert_eq!(res.num_hole(), 0);
+        assert!((res.area() - 169.).abs() < 1e-9);
+    }
+}
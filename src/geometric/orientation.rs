@@ -0,0 +1,215 @@
+use super::utils::{signed_area, to_point_list, to_ring};
+use super::{ConvexPolygon, GeneralPolygon, SimplePolygon, SinglePolygon};
+use geo::{LineString, Polygon};
+
+// 环的方向
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Winding {
+    // 逆时针
+    #[default]
+    Ccw,
+    // 顺时针
+    Cw,
+}
+
+impl Winding {
+    pub fn reverse(self) -> Self {
+        match self {
+            Winding::Ccw => Winding::Cw,
+            Winding::Cw => Winding::Ccw,
+        }
+    }
+}
+
+// 环的方向与规范化
+// signed_area 为各环有向面积之和, 逆时针为正; 外环逆时针, 洞顺时针时等于 area()
+// is_ccw 只看外环
+pub trait Orientation: Sized {
+    fn signed_area(&self) -> f64;
+
+    fn is_ccw(&self) -> bool {
+        self.signed_area() > 0.
+    }
+
+    // 外环为 exterior 方向, 洞为相反方向
+    fn normalize_with(&self, exterior: Winding) -> Self;
+
+    // 外环逆时针, 洞顺时针 (GeoJSON 的约定)
+    fn normalize(&self) -> Self {
+        self.normalize_with(Winding::Ccw)
+    }
+
+    // 所有环反向
+    fn reverse(&self) -> Self;
+}
+
+fn reverse_line(line: &LineString<f64>) -> LineString<f64> {
+    let mut pts = to_point_list(line);
+    pts.reverse();
+    LineString::from(pts)
+}
+
+fn orient_line(line: &LineString<f64>, winding: Winding) -> LineString<f64> {
+    let area = signed_area(&to_ring(line));
+    match winding {
+        Winding::Ccw if area < 0. => reverse_line(line),
+        Winding::Cw if area > 0. => reverse_line(line),
+        _ => line.clone(),
+    }
+}
+
+fn ring_area(pol: &Polygon<f64>) -> f64 {
+    signed_area(&to_ring(pol.exterior()))
+}
+
+fn orient_polygon(pol: &Polygon<f64>, exterior: Winding) -> Polygon<f64> {
+    Polygon::new(
+        orient_line(pol.exterior(), exterior),
+        pol.interiors()
+            .iter()
+            .map(|line| orient_line(line, exterior.reverse()))
+            .collect(),
+    )
+}
+
+fn reverse_polygon(pol: &Polygon<f64>) -> Polygon<f64> {
+    Polygon::new(
+        reverse_line(pol.exterior()),
+        pol.interiors().iter().map(reverse_line).collect(),
+    )
+}
+
+impl Orientation for LineString<f64> {
+    fn signed_area(&self) -> f64 {
+        signed_area(&to_ring(self))
+    }
+
+    fn normalize_with(&self, exterior: Winding) -> Self {
+        orient_line(self, exterior)
+    }
+
+    fn reverse(&self) -> Self {
+        reverse_line(self)
+    }
+}
+
+impl Orientation for SimplePolygon {
+    fn signed_area(&self) -> f64 {
+        ring_area(&self._base_polygon)
+    }
+
+    fn normalize_with(&self, exterior: Winding) -> Self {
+        SimplePolygon {
+            _base_polygon: orient_polygon(&self._base_polygon, exterior),
+        }
+    }
+
+    fn reverse(&self) -> Self {
+        SimplePolygon {
+            _base_polygon: reverse_polygon(&self._base_polygon),
+        }
+    }
+}
+
+impl Orientation for ConvexPolygon {
+    fn signed_area(&self) -> f64 {
+        ring_area(&self._base_polygon)
+    }
+
+    fn normalize_with(&self, exterior: Winding) -> Self {
+        ConvexPolygon {
+            _base_polygon: orient_polygon(&self._base_polygon, exterior),
+        }
+    }
+
+    fn reverse(&self) -> Self {
+        ConvexPolygon {
+            _base_polygon: reverse_polygon(&self._base_polygon),
+        }
+    }
+}
+
+impl Orientation for SinglePolygon {
+    fn signed_area(&self) -> f64 {
+        self._base_polygon
+            .interiors()
+            .iter()
+            .fold(ring_area(&self._base_polygon), |total, line| {
+                total + signed_area(&to_ring(line))
+            })
+    }
+
+    fn is_ccw(&self) -> bool {
+        ring_area(&self._base_polygon) > 0.
+    }
+
+    fn normalize_with(&self, exterior: Winding) -> Self {
+        SinglePolygon {
+            _base_polygon: orient_polygon(&self._base_polygon, exterior),
+        }
+    }
+
+    fn reverse(&self) -> Self {
+        SinglePolygon {
+            _base_polygon: reverse_polygon(&self._base_polygon),
+        }
+    }
+}
+
+// 所有多边形的外环都为逆时针时 is_ccw 为真
+impl Orientation for GeneralPolygon {
+    fn signed_area(&self) -> f64 {
+        self.polygons.iter().map(|pol| pol.signed_area()).sum()
+    }
+
+    fn is_ccw(&self) -> bool {
+        self.polygons.iter().all(|pol| pol.is_ccw())
+    }
+
+    fn normalize_with(&self, exterior: Winding) -> Self {
+        self.polygons
+            .iter()
+            .map(|pol| pol.normalize_with(exterior))
+            .collect()
+    }
+
+    fn reverse(&self) -> Self {
+        self.polygons.iter().map(|pol| pol.reverse()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Orientation, Winding};
+    use crate::geometric::{FromPoints, SinglePolygon};
+
+    #[test]
+    fn test_orientation() {
+        // 外环顺时针, 洞逆时针
+        let pol = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0)],
+            vec![vec![(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 2.0)]],
+        );
+        assert!(!pol.is_ccw());
+        assert_eq!(pol.signed_area(), -15.);
+        // area 沿用 geo 的结果, 减去的是洞的有向面积
+        assert_eq!(pol.area(), -17.);
+
+        let res = pol.normalize();
+        assert!(res.is_ccw());
+        assert_eq!(res.signed_area(), 15.);
+        assert!(!res.hole(0).unwrap().is_ccw());
+        assert_eq!(res.area(), 17.);
+        // 已经规范的多边形不变
+        assert_eq!(res.normalize(), res);
+
+        let res = pol.normalize_with(Winding::Cw);
+        assert!(!res.is_ccw());
+        assert!(res.hole(0).unwrap().is_ccw());
+        assert_eq!(res.signed_area(), -15.);
+
+        let res = pol.reverse();
+        assert_eq!(res, pol.normalize());
+        assert_eq!(res.reverse(), pol);
+    }
+}
@@ -0,0 +1,157 @@
+use super::utils::{point_in_rings, segment_distance, to_ring};
+use super::SinglePolygon;
+use crate::base::Point2;
+use geo::Point;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+// 正方形网格单元, half 为半边长
+// dist 为中心到边界的有向距离 (内部为正), max 为单元内可能达到的最大距离
+struct Cell {
+    center: Point<f64>,
+    half: f64,
+    dist: f64,
+    max: f64,
+}
+
+impl Cell {
+    fn new(center: Point<f64>, half: f64, rings: &[Vec<Point<f64>>]) -> Self {
+        let dist = signed_distance(rings, &center);
+        Cell {
+            center,
+            half,
+            dist,
+            max: dist + half * std::f64::consts::SQRT_2,
+        }
+    }
+}
+
+impl PartialEq for Cell {
+    fn eq(&self, other: &Self) -> bool {
+        self.max == other.max
+    }
+}
+
+impl Eq for Cell {}
+
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.max.partial_cmp(&other.max).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl SinglePolygon {
+    // 内部离边界 (含洞的边) 最远的点及该距离, 即最大内切圆的圆心和半径
+    // 网格逐步细分 (polylabel), 结果与最优值之差不超过 precision
+    pub fn pole_of_inaccessibility(&self, precision: f64) -> Option<(Point2, f64)> {
+        let rings: Vec<Vec<Point<f64>>> = std::iter::once(self._base_polygon.exterior())
+            .chain(self._base_polygon.interiors().iter())
+            .map(to_ring)
+            .collect();
+        if rings[0].len() < 3 {
+            return None;
+        }
+        let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+        let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for pt in rings[0].iter() {
+            min_x = min_x.min(pt.x());
+            min_y = min_y.min(pt.y());
+            max_x = max_x.max(pt.x());
+            max_y = max_y.max(pt.y());
+        }
+        let size = (max_x - min_x).min(max_y - min_y);
+        if size <= 0. {
+            return Some((Point2::new(min_x, min_y), 0.));
+        }
+
+        let half = size / 2.;
+        let mut queue = BinaryHeap::new();
+        let mut x = min_x;
+        while x < max_x {
+            let mut y = min_y;
+            while y < max_y {
+                queue.push(Cell::new(Point::new(x + half, y + half), half, &rings));
+                y += size;
+            }
+            x += size;
+        }
+
+        let mut best = Cell::new(
+            Point::new((min_x + max_x) / 2., (min_y + max_y) / 2.),
+            0.,
+            &rings,
+        );
+        let precision = precision.max(size * 1e-9);
+        while let Some(cell) = queue.pop() {
+            if cell.dist > best.dist {
+                best = Cell::new(cell.center, 0., &rings);
+            }
+            if cell.max - best.dist <= precision {
+                continue;
+            }
+            let half = cell.half / 2.;
+            for &(dx, dy) in [(-1., -1.), (1., -1.), (-1., 1.), (1., 1.)].iter() {
+                let center = Point::new(cell.center.x() + dx * half, cell.center.y() + dy * half);
+                queue.push(Cell::new(center, half, &rings));
+            }
+        }
+        Some((Point2::new(best.center.x(), best.center.y()), best.dist))
+    }
+}
+
+// 到所有环的最近距离, 在区域内为正
+fn signed_distance(rings: &[Vec<Point<f64>>], pt: &Point<f64>) -> f64 {
+    let dist = rings
+        .iter()
+        .flat_map(|ring| {
+            let n = ring.len();
+            (0..n).map(move |i| segment_distance(&ring[i], &ring[(i + 1) % n], pt))
+        })
+        .fold(f64::INFINITY, f64::min);
+    if point_in_rings(rings, pt) {
+        dist
+    } else {
+        -dist
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometric::{FromPoints, Locate, SinglePolygon};
+
+    #[test]
+    fn test_pole_of_inaccessibility() {
+        // L 形, 顶点平均值 (1.67, 1.67) 在区域外
+        let pol = SinglePolygon::from_points(
+            vec![
+                (0.0, 0.0),
+                (4.0, 0.0),
+                (4.0, 1.0),
+                (1.0, 1.0),
+                (1.0, 4.0),
+                (0.0, 4.0),
+            ],
+            vec![],
+        );
+        let (pt, dist) = pol.pole_of_inaccessibility(1e-3).unwrap();
+        let sqrt_2 = std::f64::consts::SQRT_2;
+        // 拐角处到两条外边和凹顶点的距离相等
+        assert!((dist - sqrt_2 / (1. + sqrt_2)).abs() < 1e-3);
+        assert!(pol.contains(&pt));
+
+        // 中心有洞时圆心在洞与外环之间
+        let pol = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![vec![(3.0, 3.0), (7.0, 3.0), (7.0, 7.0), (3.0, 7.0)]],
+        );
+        let (pt, dist) = pol.pole_of_inaccessibility(1e-3).unwrap();
+        assert!(pol.contains(&pt));
+        assert!((dist - 3. * sqrt_2 / (1. + sqrt_2)).abs() < 1e-3);
+    }
+}
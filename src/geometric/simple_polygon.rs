@@ -1,10 +1,13 @@
-use super::utils::to_point_list;
-use super::ConvexPolygon;
+use super::simplicity::find_self_intersection;
+use super::deserialize::Ring;
+use super::utils::{ring_edges, to_point_list, to_ring};
+use super::{ConvexPolygon, FromPoint, PolygonError};
+use crate::base::Segment2;
 use geo::area::Area;
 use geo::{LineString, Point, Polygon};
 use std::fmt;
 
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde::ser::SerializeSeq;
 
 // use serde::{Deserialize, Serialize};
@@ -23,6 +26,39 @@ impl SimplePolygon {
         }
     }
 
+    // 去掉连续重复点, 并检查是否自相交
+    pub fn try_from_points<T>(ex: T) -> Result<Self, PolygonError>
+    where
+        Self: FromPoint<T>,
+    {
+        // 记下每个点在输入中最后一次出现的位置, 边 i 从这个位置出发
+        let mut pts = vec![];
+        let mut pos: Vec<usize> = vec![];
+        for (i, pt) in to_ring(Self::from_points(ex).exterior()).into_iter().enumerate() {
+            if pts.last() == Some(&pt) {
+                let last = pos.len() - 1;
+                pos[last] = i;
+            } else {
+                pts.push(pt);
+                pos.push(i);
+            }
+        }
+        while pts.len() > 1 && pts[0] == pts[pts.len() - 1] {
+            pts.pop();
+            pos.pop();
+        }
+        if pts.len() < 3 {
+            return Err(PolygonError::TooFewVertices(pts.len()));
+        }
+        if let Some((first, second)) = find_self_intersection(&pts) {
+            return Err(PolygonError::SelfIntersection {
+                first: pos[first],
+                second: pos[second],
+            });
+        }
+        Ok(SimplePolygon::new(LineString::from(pts)))
+    }
+
     fn exterior(&self) -> &LineString<f64> {
         self._base_polygon.exterior()
     }
@@ -35,6 +71,10 @@ impl SimplePolygon {
         to_point_list(self.exterior())
     }
 
+    pub fn edges(&self) -> impl Iterator<Item = Segment2> + '_ {
+        ring_edges(self.exterior())
+    }
+
     pub fn has_hole(&self) -> bool {
         false
     }
@@ -52,7 +92,7 @@ impl SimplePolygon {
     }
 
     pub fn is_simple(&self) -> bool {
-        true
+        find_self_intersection(&to_ring(self.exterior())).is_none()
     }
 
     pub fn is_convex(&self) -> bool {
@@ -94,3 +134,77 @@ impl Serialize for SimplePolygon {
         seq.end()
     }
 }
+
+impl<'de> Deserialize<'de> for SimplePolygon {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // 与 try_from_points 相同的检查
+        let Ring(ex) = Ring::deserialize(deserializer)?;
+        SimplePolygon::try_from_points(to_point_list(&ex)).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SimplePolygon;
+    use crate::geometric::PolygonError;
+
+    #[test]
+    fn test_try_from_points() {
+        let pol = SimplePolygon::try_from_points(vec![
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 4.0),
+            (0.0, 4.0),
+        ])
+        .unwrap();
+        assert!(pol.is_simple());
+        assert_eq!(pol.vertices().len(), 5);
+
+        let res =
+            SimplePolygon::try_from_points(vec![(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)]);
+        assert_eq!(
+            res,
+            Err(PolygonError::SelfIntersection {
+                first: 0,
+                second: 2
+            })
+        );
+        // 边的编号对应输入中的位置, 重复点也计入
+        let res = SimplePolygon::try_from_points(vec![
+            (0.0, 0.0),
+            (0.0, 0.0),
+            (2.0, 2.0),
+            (2.0, 0.0),
+            (2.0, 0.0),
+            (0.0, 2.0),
+        ]);
+        assert_eq!(
+            res,
+            Err(PolygonError::SelfIntersection {
+                first: 1,
+                second: 4
+            })
+        );
+        let res = SimplePolygon::try_from_points(vec![(0.0, 0.0), (1.0, 1.0), (0.0, 0.0)]);
+        assert_eq!(res, Err(PolygonError::TooFewVertices(2)));
+    }
+
+    #[test]
+    fn test_simple_polygon_serde() {
+        let pol =
+            SimplePolygon::try_from_points(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (1.0, 2.0)])
+                .unwrap();
+        let text = serde_json::to_string(&pol).unwrap();
+        let res: SimplePolygon = serde_json::from_str(&text).unwrap();
+        assert_eq!(res, pol);
+
+        assert!(serde_json::from_str::<SimplePolygon>("[]").is_err());
+        assert!(serde_json::from_str::<SimplePolygon>("[[0, 0], [1, 1], [0, 0]]").is_err());
+        // 自相交
+        assert!(serde_json::from_str::<SimplePolygon>("[0, 0, 2, 2, 2, 0, 0, 2]").is_err());
+    }
+}
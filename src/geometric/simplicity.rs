@@ -0,0 +1,244 @@
+use super::utils::{cross, segments_intersect};
+use crate::base::EPSILON;
+use geo::Point;
+use std::cmp::Ordering;
+
+// Shamos-Hoey 扫描线, 检测环是否自相交
+// ring 不含闭合点, 边 i 为 ring[i] -> ring[(i + 1) % n]
+// 返回第一对相交的边 (i < j)
+pub(crate) fn find_self_intersection(ring: &[Point<f64>]) -> Option<(usize, usize)> {
+    let n = ring.len();
+    if n < 3 {
+        return None;
+    }
+
+    // 相邻边只在公共顶点相接, 共线折返时重叠
+    for i in 0..n {
+        let a = &ring[i];
+        let b = &ring[(i + 1) % n];
+        let c = &ring[(i + 2) % n];
+        let (ab, bc) = (
+            (b.x() - a.x(), b.y() - a.y()),
+            (c.x() - b.x(), c.y() - b.y()),
+        );
+        if cross(a, b, c).abs() <= EPSILON && ab.0 * bc.0 + ab.1 * bc.1 < 0. {
+            let j = (i + 1) % n;
+            return Some((i.min(j), i.max(j)));
+        }
+    }
+
+    let edges: Vec<Edge> = (0..n)
+        .map(|i| Edge::new(i, ring[i], ring[(i + 1) % n]))
+        .collect();
+
+    let mut events: Vec<Event> = Vec::with_capacity(2 * n);
+    for edge in edges.iter() {
+        events.push(Event {
+            pt: edge.left,
+            is_left: true,
+            edge: edge.id,
+        });
+        events.push(Event {
+            pt: edge.right,
+            is_left: false,
+            edge: edge.id,
+        });
+    }
+    events.sort_by(|a, b| a.order(b));
+
+    let is_adjacent = |i: usize, j: usize| (i + 1) % n == j || (j + 1) % n == i;
+    let check = |i: usize, j: usize| -> Option<(usize, usize)> {
+        if i == j || is_adjacent(i, j) {
+            return None;
+        }
+        let (a, b) = (&edges[i], &edges[j]);
+        if segments_intersect(&a.left, &a.right, &b.left, &b.right) {
+            Some((i.min(j), i.max(j)))
+        } else {
+            None
+        }
+    };
+
+    // 扫描线状态, 按当前 x 处的 y 值从下到上
+    let mut status: Vec<usize> = vec![];
+    for event in events.iter() {
+        let x = event.pt.x();
+        if event.is_left {
+            let edge = &edges[event.edge];
+            let pos = status
+                .binary_search_by(|&other| edges[other].cmp_at(edge, x))
+                .unwrap_or_else(|pos| pos);
+            status.insert(pos, edge.id);
+
+            // 上下邻居, 以及同一点处的所有边
+            let y = edge.y_at(x);
+            let mut lo = pos;
+            while lo > 0 {
+                lo -= 1;
+                if let Some(res) = check(edge.id, status[lo]) {
+                    return Some(res);
+                }
+                if (edges[status[lo]].y_at(x) - y).abs() > EPSILON {
+                    break;
+                }
+            }
+            let mut hi = pos + 1;
+            while hi < status.len() {
+                if let Some(res) = check(edge.id, status[hi]) {
+                    return Some(res);
+                }
+                if (edges[status[hi]].y_at(x) - y).abs() > EPSILON {
+                    break;
+                }
+                hi += 1;
+            }
+        } else {
+            let pos = match status.iter().position(|&id| id == event.edge) {
+                Some(pos) => pos,
+                None => continue,
+            };
+            status.remove(pos);
+            if pos > 0 && pos < status.len() {
+                if let Some(res) = check(status[pos - 1], status[pos]) {
+                    return Some(res);
+                }
+            }
+        }
+    }
+    None
+}
+
+struct Edge {
+    id: usize,
+    left: Point<f64>,
+    right: Point<f64>,
+}
+
+impl Edge {
+    fn new(id: usize, a: Point<f64>, b: Point<f64>) -> Self {
+        if cmp_point(&a, &b) == Ordering::Greater {
+            Edge {
+                id,
+                left: b,
+                right: a,
+            }
+        } else {
+            Edge {
+                id,
+                left: a,
+                right: b,
+            }
+        }
+    }
+
+    fn y_at(&self, x: f64) -> f64 {
+        let dx = self.right.x() - self.left.x();
+        if dx.abs() <= EPSILON {
+            return self.left.y();
+        }
+        let t = ((x - self.left.x()) / dx).clamp(0., 1.);
+        self.left.y() + t * (self.right.y() - self.left.y())
+    }
+
+    fn slope(&self) -> f64 {
+        let dx = self.right.x() - self.left.x();
+        if dx.abs() <= EPSILON {
+            f64::INFINITY
+        } else {
+            (self.right.y() - self.left.y()) / dx
+        }
+    }
+
+    fn cmp_at(&self, other: &Edge, x: f64) -> Ordering {
+        let (y_0, y_1) = (self.y_at(x), other.y_at(x));
+        if (y_0 - y_1).abs() > EPSILON {
+            return y_0.partial_cmp(&y_1).unwrap_or(Ordering::Equal);
+        }
+        self.slope()
+            .partial_cmp(&other.slope())
+            .unwrap_or(Ordering::Equal)
+            .then(self.id.cmp(&other.id))
+    }
+}
+
+struct Event {
+    pt: Point<f64>,
+    is_left: bool,
+    edge: usize,
+}
+
+impl Event {
+    // 同一点处先插入再删除, 保证在该点接触的边都能被比较
+    fn order(&self, other: &Event) -> Ordering {
+        cmp_point(&self.pt, &other.pt).then(other.is_left.cmp(&self.is_left))
+    }
+}
+
+fn cmp_point(a: &Point<f64>, b: &Point<f64>) -> Ordering {
+    a.x()
+        .partial_cmp(&b.x())
+        .unwrap_or(Ordering::Equal)
+        .then(a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal))
+}
+
+#[cfg(test)]
+mod test {
+    use super::find_self_intersection;
+    use geo::Point;
+
+    fn ring(pts: &[(f64, f64)]) -> Vec<Point<f64>> {
+        pts.iter().map(|&(x, y)| Point::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_self_intersection() {
+        let l_shape = ring(&[
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 4.0),
+            (0.0, 4.0),
+        ]);
+        assert_eq!(find_self_intersection(&l_shape), None);
+
+        let bow_tie = ring(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)]);
+        assert_eq!(find_self_intersection(&bow_tie), Some((0, 2)));
+
+        // 顶点落在另一条边上
+        let touching = ring(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (2.0, 0.0), (0.0, 4.0)]);
+        assert!(find_self_intersection(&touching).is_some());
+
+        let spike = ring(&[(0.0, 0.0), (2.0, 0.0), (1.0, 0.0), (1.0, 1.0)]);
+        assert!(find_self_intersection(&spike).is_some());
+    }
+
+    #[test]
+    fn test_self_intersection_matches_brute_force() {
+        use crate::geometric::utils::segments_intersect;
+        // 伪随机折线
+        let mut seed = 7u64;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % 1000) as f64 / 10.
+        };
+        for _ in 0..200 {
+            let pts: Vec<Point<f64>> = (0..7).map(|_| Point::new(next(), next())).collect();
+            let n = pts.len();
+            let mut brute = false;
+            for i in 0..n {
+                for j in i + 1..n {
+                    if (i + 1) % n == j || (j + 1) % n == i {
+                        continue;
+                    }
+                    if segments_intersect(&pts[i], &pts[(i + 1) % n], &pts[j], &pts[(j + 1) % n]) {
+                        brute = true;
+                    }
+                }
+            }
+            assert_eq!(find_self_intersection(&pts).is_some(), brute);
+        }
+    }
+}
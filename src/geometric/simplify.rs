@@ -0,0 +1,367 @@
+use super::utils::{cross, point_in_rings, segment_distance, segments_intersect, to_ring};
+use super::{SimplePolygon, SinglePolygon};
+use geo::{LineString, Point};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum SimplifyMode {
+    // tolerance 为去掉的点到简化后边的最大距离
+    DouglasPeucker,
+    // tolerance 为去掉的点与相邻点组成的三角形的最大有效面积
+    VisvalingamWhyatt,
+}
+
+// preserve_topology 为 true 时保证结果仍为简单多边形, 且洞仍在外环内, 洞之间互不包含
+// 每个环至少保留 3 个顶点
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct SimplifyOptions {
+    pub mode: SimplifyMode,
+    pub tolerance: f64,
+    pub preserve_topology: bool,
+}
+
+impl Default for SimplifyOptions {
+    fn default() -> Self {
+        SimplifyOptions {
+            mode: SimplifyMode::DouglasPeucker,
+            tolerance: 1.,
+            preserve_topology: true,
+        }
+    }
+}
+
+pub trait Simplify {
+    fn simplify_with(&self, options: &SimplifyOptions) -> Self;
+
+    fn simplify(&self, tolerance: f64) -> Self
+    where
+        Self: Sized,
+    {
+        self.simplify_with(&SimplifyOptions {
+            tolerance,
+            ..SimplifyOptions::default()
+        })
+    }
+}
+
+impl Simplify for SimplePolygon {
+    fn simplify_with(&self, options: &SimplifyOptions) -> Self {
+        let mut rings = simplify_rings(&[to_ring(self._base_polygon.exterior())], options);
+        SimplePolygon::new(LineString::from(rings.remove(0)))
+    }
+}
+
+impl Simplify for SinglePolygon {
+    fn simplify_with(&self, options: &SimplifyOptions) -> Self {
+        let rings: Vec<Vec<Point<f64>>> = std::iter::once(self._base_polygon.exterior())
+            .chain(self._base_polygon.interiors().iter())
+            .map(to_ring)
+            .collect();
+        let mut rings = simplify_rings(&rings, options).into_iter();
+        let ex = LineString::from(rings.next().unwrap());
+        SinglePolygon::new(ex, rings.map(LineString::from).collect())
+    }
+}
+
+pub(crate) fn simplify_rings(
+    rings: &[Vec<Point<f64>>],
+    options: &SimplifyOptions,
+) -> Vec<Vec<Point<f64>>> {
+    let rings: Vec<Vec<Point<f64>>> = rings
+        .iter()
+        .map(|ring| {
+            let mut ring = ring.clone();
+            ring.dedup();
+            ring
+        })
+        .collect();
+    let mut keep: Vec<Vec<bool>> = rings
+        .iter()
+        .map(|ring| match options.mode {
+            SimplifyMode::DouglasPeucker => douglas_peucker(ring, options.tolerance),
+            SimplifyMode::VisvalingamWhyatt => visvalingam_whyatt(ring, options.tolerance),
+        })
+        .collect();
+    if options.preserve_topology {
+        repair(&rings, &mut keep);
+    }
+    rings
+        .iter()
+        .zip(keep.iter())
+        .map(|(ring, mask)| {
+            ring.iter()
+                .zip(mask.iter())
+                .filter(|(_, &k)| k)
+                .map(|(pt, _)| *pt)
+                .collect()
+        })
+        .collect()
+}
+
+// 环上 a 到 b 之间 (不含两端) 离线段 ab 最远的点
+fn farthest(ring: &[Point<f64>], a: usize, b: usize) -> Option<(usize, f64)> {
+    let n = ring.len();
+    let mut k = (a + 1) % n;
+    let mut res: Option<(usize, f64)> = None;
+    while k != b % n {
+        let d = segment_distance(&ring[a], &ring[b % n], &ring[k]);
+        match res {
+            Some((_, best)) if best >= d => {}
+            _ => res = Some((k, d)),
+        }
+        k = (k + 1) % n;
+    }
+    res
+}
+
+// 以第 0 个点和离它最远的点把环分成两条折线, 分别递归
+fn douglas_peucker(ring: &[Point<f64>], tolerance: f64) -> Vec<bool> {
+    let n = ring.len();
+    if n <= 3 {
+        return vec![true; n];
+    }
+    let far = (1..n)
+        .max_by(|&i, &j| {
+            let d = |k: usize| (ring[k].x() - ring[0].x()).hypot(ring[k].y() - ring[0].y());
+            d(i).partial_cmp(&d(j)).unwrap_or(Ordering::Equal)
+        })
+        .unwrap();
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[far] = true;
+    let mut stack = vec![(0, far), (far, n)];
+    while let Some((a, b)) = stack.pop() {
+        if let Some((k, d)) = farthest(ring, a, b) {
+            if d > tolerance {
+                keep[k] = true;
+                stack.push((a, k));
+                stack.push((k, b));
+            }
+        }
+    }
+    if keep.iter().filter(|&&k| k).count() < 3 {
+        let (a, b) = if far > 1 { (0, far) } else { (far, n) };
+        if let Some((k, _)) = farthest(ring, a, b) {
+            keep[k] = true;
+        }
+    }
+    keep
+}
+
+struct Candidate {
+    area: f64,
+    index: usize,
+    stamp: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// 面积小的先出堆
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .partial_cmp(&self.area)
+            .unwrap_or(Ordering::Equal)
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+// 每次去掉有效面积最小的点, 有效面积不小于之前去掉的点
+fn visvalingam_whyatt(ring: &[Point<f64>], tolerance: f64) -> Vec<bool> {
+    let n = ring.len();
+    let mut keep = vec![true; n];
+    if n <= 3 {
+        return keep;
+    }
+    let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+    let mut stamps = vec![0; n];
+    let area = |p: usize, c: usize, q: usize| cross(&ring[p], &ring[c], &ring[q]).abs() / 2.;
+    let mut heap: BinaryHeap<Candidate> = (0..n)
+        .map(|i| Candidate {
+            area: area(prev[i], i, next[i]),
+            index: i,
+            stamp: 0,
+        })
+        .collect();
+
+    let mut left = n;
+    let mut last = 0f64;
+    while let Some(cand) = heap.pop() {
+        let i = cand.index;
+        if !keep[i] || cand.stamp != stamps[i] {
+            continue;
+        }
+        if left <= 3 || cand.area > tolerance {
+            break;
+        }
+        last = last.max(cand.area);
+        keep[i] = false;
+        left -= 1;
+        let (p, q) = (prev[i], next[i]);
+        next[p] = q;
+        prev[q] = p;
+        for &k in [p, q].iter() {
+            stamps[k] += 1;
+            heap.push(Candidate {
+                area: area(prev[k], k, next[k]).max(last),
+                index: k,
+                stamp: stamps[k],
+            });
+        }
+    }
+    keep
+}
+
+// 简化后的边 (r, a, b), 环 r 上从 a 到 b
+fn simplified_edges(keep: &[Vec<bool>]) -> Vec<(usize, usize, usize)> {
+    let mut edges = vec![];
+    for (r, mask) in keep.iter().enumerate() {
+        let kept: Vec<usize> = (0..mask.len()).filter(|&i| mask[i]).collect();
+        for t in 0..kept.len() {
+            edges.push((r, kept[t], kept[(t + 1) % kept.len()]));
+        }
+    }
+    edges
+}
+
+// 简化后的边两两不相交, 且被去掉的折线与新边之间不含其他顶点
+// 否则把该折线上离新边最远的点加回, 直到没有冲突
+fn repair(rings: &[Vec<Point<f64>>], keep: &mut [Vec<bool>]) {
+    loop {
+        let edges = simplified_edges(keep);
+        let shortcut =
+            |&(r, a, b): &(usize, usize, usize)| (b + rings[r].len() - a) % rings[r].len() != 1;
+        let mut conflicts = vec![false; edges.len()];
+        for (e, edge) in edges.iter().enumerate() {
+            let (r, a, b) = *edge;
+            let ring = &rings[r];
+            for (f, other) in edges.iter().enumerate().skip(e + 1) {
+                let (s, c, d) = *other;
+                if s == r && (a == c || a == d || b == c || b == d) {
+                    continue;
+                }
+                if segments_intersect(&ring[a], &ring[b], &rings[s][c], &rings[s][d]) {
+                    conflicts[e] = true;
+                    conflicts[f] = true;
+                }
+            }
+            if !shortcut(edge) || conflicts[e] {
+                continue;
+            }
+            let n = ring.len();
+            let mut region = vec![ring[a]];
+            let mut k = (a + 1) % n;
+            while k != b {
+                region.push(ring[k]);
+                k = (k + 1) % n;
+            }
+            region.push(ring[b]);
+            let region = vec![region];
+            conflicts[e] = keep.iter().enumerate().any(|(s, mask)| {
+                (0..mask.len()).any(|i| {
+                    mask[i]
+                        && !(s == r && (i == a || i == b))
+                        && point_in_rings(&region, &rings[s][i])
+                })
+            });
+        }
+
+        let mut changed = false;
+        for (e, &(r, a, b)) in edges.iter().enumerate() {
+            if conflicts[e] && shortcut(&(r, a, b)) {
+                if let Some((k, _)) = farthest(&rings[r], a, b) {
+                    keep[r][k] = true;
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Simplify, SimplifyMode, SimplifyOptions};
+    use crate::geometric::{FromPoint, FromPoints, SimplePolygon, SinglePolygon};
+
+    #[test]
+    fn test_simplify() {
+        // 每条边上都有抖动的正方形
+        let mut pts = vec![];
+        for i in 0..40 {
+            let t = (i % 10) as f64 * 0.4;
+            let wiggle = if i % 2 == 0 { 0. } else { 0.01 };
+            let (x, y) = match i / 10 {
+                0 => (t, wiggle),
+                1 => (4. - wiggle, t),
+                2 => (4. - t, 4. - wiggle),
+                _ => (wiggle, 4. - t),
+            };
+            pts.push((x, y));
+        }
+        let pol = SimplePolygon::from_points(pts);
+        for &mode in [
+            SimplifyMode::DouglasPeucker,
+            SimplifyMode::VisvalingamWhyatt,
+        ]
+        .iter()
+        {
+            let res = pol.simplify_with(&SimplifyOptions {
+                mode,
+                tolerance: 0.05,
+                preserve_topology: false,
+            });
+            assert_eq!(res.vertices().len(), 5, "{:?}", mode);
+            assert!((res.area() - 16.).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_simplify_preserve_topology() {
+        // 洞在外环的尖刺里, 直接简化会去掉尖刺, 洞落到外面
+        let pol = SinglePolygon::from_points(
+            vec![
+                (0.0, 0.0),
+                (40.0, 0.0),
+                (40.0, 40.0),
+                (20.5, 40.0),
+                (20.0, 50.0),
+                (19.5, 40.0),
+                (0.0, 40.0),
+            ],
+            vec![vec![(19.9, 44.0), (20.1, 44.0), (20.0, 46.0)]],
+        );
+        let options = SimplifyOptions {
+            tolerance: 10.5,
+            preserve_topology: false,
+            ..SimplifyOptions::default()
+        };
+        let res = pol.simplify_with(&options);
+        assert_eq!(res.vertices().len(), 5);
+
+        let res = pol.simplify_with(&SimplifyOptions {
+            preserve_topology: true,
+            ..options
+        });
+        assert_eq!(res.vertices().len(), 6);
+        assert!(res.out_polygon().is_simple());
+        assert_eq!(res.hole(0).unwrap().vertices().len(), 4);
+    }
+}
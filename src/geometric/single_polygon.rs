@@ -1,8 +1,10 @@
-use super::utils::to_point_list;
-use super::{ConvexPolygon, Draw, SimplePolygon};
-use crate::base::Color;
+use super::deserialize::Ring;
+use super::utils::{ring_edges, to_point_list};
+use super::{ConvexPolygon, Draw, Orientation, SimplePolygon};
+use crate::base::{Color, Segment2};
 use geo::area::Area;
 use geo::{LineString, Point, Polygon};
+use serde::de;
 use serde::ser::SerializeSeq;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::rc::Rc;
@@ -38,6 +40,13 @@ impl SinglePolygon {
         to_point_list(self.exterior())
     }
 
+    // 外环的边, 然后依次为各个洞的边
+    pub fn edges(&self) -> impl Iterator<Item = Segment2> + '_ {
+        std::iter::once(self.exterior())
+            .chain(self.interior().iter())
+            .flat_map(ring_edges)
+    }
+
     pub fn has_hole(&self) -> bool {
         self.interior().len() > 0
     }
@@ -114,7 +123,9 @@ impl Draw for SinglePolygon {
         let mut start = Point::<f64>::new(0., 0.);
         let mut end = Point::<f64>::new(0., 0.);
 
-        let mut pts = to_point_list(self.exterior());
+        // 非零环绕规则下洞需要与外环反向才会被挖空
+        let pol = self.normalize();
+        let mut pts = to_point_list(pol.exterior());
         for (i, pt) in pts.iter().enumerate() {
             if i == 0 {
                 start = pt.clone();
@@ -129,7 +140,7 @@ impl Draw for SinglePolygon {
             ctx.line_to(start.x(), start.y());
         }
 
-        for lines in self.interior().iter() {
+        for lines in pol.interior().iter() {
             pts = to_point_list(lines);
             for (i, pt) in pts.iter().enumerate() {
                 ctx.line_to(pt.x(), pt.y());
@@ -166,10 +177,31 @@ impl Serialize for SinglePolygon {
     }
 }
 
+impl<'de> Deserialize<'de> for SinglePolygon {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // 外环和每个洞都做与 SimplePolygon 相同的检查
+        let check = |Ring(line): Ring| {
+            SimplePolygon::try_from_points(to_point_list(&line))
+                .map(|_| line)
+                .map_err(de::Error::custom)
+        };
+        let mut rings = Vec::<Ring>::deserialize(deserializer)?.into_iter();
+        let ex = match rings.next() {
+            Some(ring) => check(ring)?,
+            None => return Err(de::Error::invalid_length(0, &"at least one ring")),
+        };
+        let holes = rings.map(check).collect::<Result<Vec<_>, _>>()?;
+        Ok(SinglePolygon::new(ex, holes))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::SinglePolygon;
-    use crate::geometric::FromPoints;
+    use crate::geometric::{FromPoints, GeneralPolygon};
     #[test]
     fn test_single_polygon() {
         let poly = SinglePolygon::from_points(
@@ -185,5 +217,37 @@ mod test {
             vec![],
         );
         assert_eq!(poly.area(), 7.0f64);
+        assert_eq!(poly.edges().count(), 6);
+        let length: f64 = poly.edges().map(|e| e.length()).sum();
+        assert_eq!(length, 16.0f64);
+    }
+
+    #[test]
+    fn test_single_polygon_serde() {
+        let poly = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)],
+            vec![vec![(1.0, 1.0), (1.0, 2.0), (2.0, 2.0), (2.0, 1.0)]],
+        );
+        let text = serde_json::to_string(&poly).unwrap();
+        let res: SinglePolygon = serde_json::from_str(&text).unwrap();
+        assert_eq!(res, poly);
+
+        let res: SinglePolygon = serde_json::from_str(
+            r#"[[[0, 0], [4, 0], [4, 4], [0, 4]], [{"x": 1, "y": 1}, {"x": 1, "y": 2}, {"x": 2, "y": 2}, {"x": 2, "y": 1}]]"#,
+        )
+        .unwrap();
+        assert_eq!(res, poly);
+
+        assert!(serde_json::from_str::<SinglePolygon>("[[0, 0, 4, 0, 4]]").is_err());
+        assert!(serde_json::from_str::<SinglePolygon>("[[0, 0, [4, 0], 4, 4]]").is_err());
+        assert!(serde_json::from_str::<SinglePolygon>("[]").is_err());
+        assert!(serde_json::from_str::<SinglePolygon>("[[]]").is_err());
+        assert!(serde_json::from_str::<SinglePolygon>("[[0, 0, 1, 1]]").is_err());
+        // 自相交的外环, 点数不足的洞
+        assert!(serde_json::from_str::<SinglePolygon>("[[0, 0, 2, 2, 2, 0, 0, 2]]").is_err());
+        assert!(
+            serde_json::from_str::<SinglePolygon>("[[0, 0, 4, 0, 4, 4, 0, 4], [1, 1]]").is_err()
+        );
+        assert!(serde_json::from_str::<GeneralPolygon>("[[[0, 0, 2, 2, 2, 0, 0, 2]]]").is_err());
     }
 }
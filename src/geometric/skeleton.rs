@@ -0,0 +1,757 @@
+use super::draw::Draw;
+use super::utils::{oriented_rings, point_in_rings};
+use super::{ConstrainedDelaunay, SimplePolygon, SinglePolygon};
+use crate::base::{AlmostEqual, Circle, Color, Point2, Vector2, EPSILON};
+use geo::{LineString, Point, Polygon};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::rc::Rc;
+
+// 骨架图, arcs 为节点下标对
+// heights 为节点到边界的距离: 直骨架中为波前到达的时间 (屋顶高度), 中轴中为内切圆半径
+#[derive(Clone, Debug, Default)]
+pub struct Skeleton {
+    pub nodes: Vec<Point2>,
+    pub heights: Vec<f64>,
+    pub arcs: Vec<(usize, usize)>,
+}
+
+pub trait StraightSkeleton {
+    // 直骨架, 前几个节点依次为外环和各个洞的顶点 (去掉重复点和共线点后)
+    // 共 O(n) 个事件, 每个事件 O(n) 更新队列, 总计 O(n^2 log n)
+    // 数值误差导致事件处理不收敛时返回 None
+    fn straight_skeleton(&self) -> Option<Skeleton>;
+
+    // 近似中轴: 边界按 spacing 采样, 取 Delaunay 三角形外心 (即采样点 Voronoi 图的顶点) 在区域内的部分
+    fn medial_axis(&self, spacing: f64) -> Skeleton;
+}
+
+impl StraightSkeleton for SinglePolygon {
+    fn straight_skeleton(&self) -> Option<Skeleton> {
+        straight_skeleton(&clean_rings(&self._base_polygon))
+    }
+
+    fn medial_axis(&self, spacing: f64) -> Skeleton {
+        medial_axis(&clean_rings(&self._base_polygon), spacing)
+    }
+}
+
+impl StraightSkeleton for SimplePolygon {
+    fn straight_skeleton(&self) -> Option<Skeleton> {
+        straight_skeleton(&clean_rings(&self._base_polygon))
+    }
+
+    fn medial_axis(&self, spacing: f64) -> Skeleton {
+        medial_axis(&clean_rings(&self._base_polygon), spacing)
+    }
+}
+
+impl Skeleton {
+    pub fn len(&self) -> usize {
+        self.arcs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.arcs.is_empty()
+    }
+
+    pub fn length(&self) -> f64 {
+        self.arcs
+            .iter()
+            .map(|&(a, b)| self.nodes[a].euclid_distance(&self.nodes[b]))
+            .sum()
+    }
+
+    // 去掉从叶子到分叉点总长小于 min_length 的分支, 例如直骨架中通向凸顶点的短枝
+    // 节点保持不变, 只删除弧
+    pub fn prune(&self, min_length: f64) -> Skeleton {
+        let mut adj: Vec<Vec<usize>> = vec![vec![]; self.nodes.len()];
+        for (k, &(a, b)) in self.arcs.iter().enumerate() {
+            adj[a].push(k);
+            adj[b].push(k);
+        }
+        let mut removed = vec![false; self.arcs.len()];
+        for leaf in 0..self.nodes.len() {
+            if adj[leaf].len() != 1 {
+                continue;
+            }
+            let (mut cur, mut arc) = (leaf, adj[leaf][0]);
+            let mut chain = vec![];
+            let mut length = 0.;
+            loop {
+                chain.push(arc);
+                let (a, b) = self.arcs[arc];
+                let other = if a == cur { b } else { a };
+                length += self.nodes[a].euclid_distance(&self.nodes[b]);
+                cur = other;
+                if adj[cur].len() != 2 {
+                    break;
+                }
+                arc = if adj[cur][0] == arc {
+                    adj[cur][1]
+                } else {
+                    adj[cur][0]
+                };
+            }
+            if adj[cur].len() >= 3 && length < min_length {
+                for k in chain {
+                    removed[k] = true;
+                }
+            }
+        }
+        Skeleton {
+            nodes: self.nodes.clone(),
+            heights: self.heights.clone(),
+            arcs: self
+                .arcs
+                .iter()
+                .zip(removed.iter())
+                .filter(|(_, &r)| !r)
+                .map(|(arc, _)| *arc)
+                .collect(),
+        }
+    }
+}
+
+impl Draw for Skeleton {
+    fn draw(&self, ctx: Rc<web_sys::CanvasRenderingContext2d>, color: &Color, _fill: bool) {
+        ctx.set_stroke_style_str(&color.to_string());
+        ctx.begin_path();
+        for &(a, b) in self.arcs.iter() {
+            ctx.move_to(self.nodes[a].x(), self.nodes[a].y());
+            ctx.line_to(self.nodes[b].x(), self.nodes[b].y());
+        }
+        ctx.stroke();
+    }
+}
+
+// 外环逆时针, 洞顺时针, 去掉重复点和共线点
+fn clean_rings(pol: &Polygon<f64>) -> Vec<Vec<Point2>> {
+    oriented_rings(pol)
+        .into_iter()
+        .map(|ring| {
+            let mut ring: Vec<Point2> = ring.iter().map(|pt| Point2::new(pt.x(), pt.y())).collect();
+            ring.dedup_by(|a, b| a.almost_equal(b));
+            while ring.len() > 1 && ring[0].almost_equal(&ring[ring.len() - 1]) {
+                ring.pop();
+            }
+            let mut k = 0;
+            while ring.len() >= 3 && k < ring.len() {
+                let n = ring.len();
+                let (a, b, c) = (&ring[(k + n - 1) % n], &ring[k], &ring[(k + 1) % n]);
+                let (u, v) = (b.from(a), c.from(b));
+                if u.exterior(&v).abs() <= EPSILON * u.l2_norm() * v.l2_norm() && u.dot(&v) > 0. {
+                    ring.remove(k);
+                } else {
+                    k += 1;
+                }
+            }
+            ring
+        })
+        .filter(|ring| ring.len() >= 3)
+        .collect()
+}
+
+// 原始边所在直线, 时刻 t 时向内平移到 normal · x = offset + t
+struct Line {
+    dir: Vector2,
+    normal: Vector2,
+    offset: f64,
+}
+
+// 波前顶点, 在 start 时刻位于 pos, 沿 velocity 匀速运动
+// in_edge, out_edge 为相邻的两条原始边
+#[derive(Clone)]
+struct Vertex {
+    pos: Point2,
+    start: f64,
+    velocity: Vector2,
+    in_edge: usize,
+    out_edge: usize,
+    prev: usize,
+    next: usize,
+    node: usize,
+    active: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Event {
+    // 边 u -> next[u] 收缩为一点
+    Edge(usize),
+    // 凹顶点 v 撞上边 a -> next[a]
+    Split(usize, usize),
+}
+
+// 队列中的事件, next 为计算时 u (或 a) 的后继
+// 顶点的后继只会改成新建的顶点, 后继不变且顶点都还活动时事件仍然有效
+struct Queued {
+    time: f64,
+    event: Event,
+    next: usize,
+}
+
+impl PartialEq for Queued {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Queued {}
+
+impl PartialOrd for Queued {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// BinaryHeap 是最大堆, 时间早的排在前面
+impl Ord for Queued {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.time.total_cmp(&self.time)
+    }
+}
+
+struct Wavefront {
+    lines: Vec<Line>,
+    vertices: Vec<Vertex>,
+    skeleton: Skeleton,
+    arc_set: HashSet<(usize, usize)>,
+    queue: BinaryHeap<Queued>,
+}
+
+impl Wavefront {
+    fn new(rings: &[Vec<Point2>]) -> Self {
+        let mut wave = Wavefront {
+            lines: vec![],
+            vertices: vec![],
+            skeleton: Skeleton::default(),
+            arc_set: HashSet::new(),
+            queue: BinaryHeap::new(),
+        };
+        for ring in rings.iter() {
+            let (start, n) = (wave.lines.len(), ring.len());
+            for i in 0..n {
+                let dir = ring[(i + 1) % n].from(&ring[i]).normalize();
+                let normal = dir.normal_vector();
+                wave.lines.push(Line {
+                    offset: normal.dot(&ring[i].from(&Point2::zero())),
+                    dir,
+                    normal,
+                });
+            }
+            for (i, pt) in ring.iter().enumerate() {
+                let in_edge = start + (i + n - 1) % n;
+                let out_edge = start + i;
+                wave.skeleton.nodes.push(pt.clone());
+                wave.skeleton.heights.push(0.);
+                wave.vertices.push(Vertex {
+                    pos: pt.clone(),
+                    start: 0.,
+                    velocity: wave.velocity(in_edge, out_edge),
+                    in_edge,
+                    out_edge,
+                    prev: start + (i + n - 1) % n,
+                    next: start + (i + 1) % n,
+                    node: start + i,
+                    active: true,
+                });
+            }
+        }
+        for u in 0..wave.vertices.len() {
+            wave.push_edge_event(u, 0.);
+            if wave.is_reflex(u) {
+                for a in 0..wave.vertices.len() {
+                    wave.push_split_event(u, a, 0.);
+                }
+            }
+        }
+        wave
+    }
+
+    // 同时在两条边的平移直线上; 平行同向时沿法向, 反向时两边已重合, 不再移动
+    fn velocity(&self, in_edge: usize, out_edge: usize) -> Vector2 {
+        let (n1, n2) = (&self.lines[in_edge].normal, &self.lines[out_edge].normal);
+        let det = n1.exterior(n2);
+        if det.abs() < EPSILON {
+            if n1.dot(n2) > 0. {
+                n1.clone()
+            } else {
+                Vector2::zero()
+            }
+        } else {
+            Vector2::new((n2.y() - n1.y()) / det, (n1.x() - n2.x()) / det)
+        }
+    }
+
+    fn position(&self, v: usize, t: f64) -> Point2 {
+        let vert = &self.vertices[v];
+        vert.pos
+            .move_along(&(vert.velocity.clone() * (t - vert.start)))
+    }
+
+    fn is_reflex(&self, v: usize) -> bool {
+        let vert = &self.vertices[v];
+        self.lines[vert.in_edge]
+            .normal
+            .exterior(&self.lines[vert.out_edge].normal)
+            < -EPSILON
+    }
+
+    fn cycle_len(&self, v: usize) -> usize {
+        let mut len = 1;
+        let mut cur = self.vertices[v].next;
+        while cur != v && len <= self.vertices.len() {
+            len += 1;
+            cur = self.vertices[cur].next;
+        }
+        len
+    }
+
+    // 同一时刻同一位置的节点只建一次
+    fn add_node(&mut self, pt: Point2, t: f64) -> usize {
+        let nodes = &self.skeleton.nodes;
+        let heights = &self.skeleton.heights;
+        if let Some(k) = (0..nodes.len())
+            .rev()
+            .take_while(|&k| heights[k] >= t - EPSILON)
+            .find(|&k| nodes[k].euclid_distance(&pt) < EPSILON.sqrt())
+        {
+            return k;
+        }
+        self.skeleton.nodes.push(pt);
+        self.skeleton.heights.push(t);
+        self.skeleton.nodes.len() - 1
+    }
+
+    fn add_arc(&mut self, a: usize, b: usize) {
+        if a != b && self.arc_set.insert((a.min(b), a.max(b))) {
+            self.skeleton.arcs.push((a, b));
+        }
+    }
+
+    // 把顶点移动到 t 时刻的位置, 返回所在的节点
+    fn settle(&mut self, v: usize, t: f64) -> usize {
+        let pt = self.position(v, t);
+        let node = self.vertices[v].node;
+        if self.skeleton.nodes[node].euclid_distance(&pt) < EPSILON.sqrt() {
+            return node;
+        }
+        let k = self.add_node(pt, t);
+        self.add_arc(node, k);
+        k
+    }
+
+    fn push_vertex(
+        &mut self,
+        pt: Point2,
+        t: f64,
+        in_edge: usize,
+        out_edge: usize,
+        node: usize,
+    ) -> usize {
+        let velocity = self.velocity(in_edge, out_edge);
+        self.vertices.push(Vertex {
+            pos: pt,
+            start: t,
+            velocity,
+            in_edge,
+            out_edge,
+            prev: 0,
+            next: 0,
+            node,
+            active: true,
+        });
+        self.vertices.len() - 1
+    }
+
+    fn link(&mut self, a: usize, b: usize) {
+        self.vertices[a].next = b;
+        self.vertices[b].prev = a;
+    }
+
+    fn edge_event(&self, u: usize, t: f64) -> Option<f64> {
+        let w = self.vertices[u].next;
+        if w == u {
+            return None;
+        }
+        let dir = &self.lines[self.vertices[u].out_edge].dir;
+        let gap = dir.dot(&self.position(w, t).from(&self.position(u, t)));
+        let closing = dir.dot(&(&self.vertices[u].velocity - &self.vertices[w].velocity));
+        if closing <= EPSILON {
+            return None;
+        }
+        Some(t + gap.max(0.) / closing)
+    }
+
+    fn split_event(&self, v: usize, a: usize, t: f64) -> Option<f64> {
+        let (vert, b) = (&self.vertices[v], self.vertices[a].next);
+        let e = self.vertices[a].out_edge;
+        if a == v || b == v || e == vert.in_edge || e == vert.out_edge {
+            return None;
+        }
+        let line = &self.lines[e];
+        let dist = line.normal.dot(&self.position(v, t).from(&Point2::zero())) - line.offset - t;
+        let speed = 1. - line.normal.dot(&vert.velocity);
+        if speed <= EPSILON || dist < -EPSILON {
+            return None;
+        }
+        let hit_time = t + dist.max(0.) / speed;
+        let hit = self.position(v, hit_time);
+        let (pa, pb) = (self.position(a, hit_time), self.position(b, hit_time));
+        let tol = EPSILON.sqrt();
+        if line.dir.dot(&hit.from(&pa)) >= -tol && line.dir.dot(&hit.from(&pb)) <= tol {
+            Some(hit_time)
+        } else {
+            None
+        }
+    }
+
+    fn push_edge_event(&mut self, u: usize, t: f64) {
+        if let Some(time) = self.edge_event(u, t) {
+            let next = self.vertices[u].next;
+            self.queue.push(Queued {
+                time,
+                event: Event::Edge(u),
+                next,
+            });
+        }
+    }
+
+    fn push_split_event(&mut self, v: usize, a: usize, t: f64) {
+        if let Some(time) = self.split_event(v, a, t) {
+            let next = self.vertices[a].next;
+            self.queue.push(Queued {
+                time,
+                event: Event::Split(v, a),
+                next,
+            });
+        }
+    }
+
+    // 顶点 x 新建或后继改变后, 重新计算与 x 和边 x -> next[x] 有关的事件, O(n)
+    fn update(&mut self, x: usize, t: f64) {
+        if !self.vertices[x].active {
+            return;
+        }
+        self.push_edge_event(x, t);
+        let active: Vec<usize> = (0..self.vertices.len())
+            .filter(|&v| self.vertices[v].active)
+            .collect();
+        let reflex = self.is_reflex(x);
+        for &v in active.iter() {
+            if reflex {
+                self.push_split_event(x, v, t);
+            }
+            if self.is_reflex(v) {
+                self.push_split_event(v, x, t);
+            }
+        }
+    }
+
+    fn is_valid(&self, queued: &Queued) -> bool {
+        let (u, others) = match queued.event {
+            Event::Edge(u) => (u, None),
+            Event::Split(v, a) => (a, Some(v)),
+        };
+        let next = queued.next;
+        self.vertices[u].active
+            && self.vertices[u].next == next
+            && self.vertices[next].active
+            && others.map_or(true, |v| self.vertices[v].active)
+    }
+
+    fn next_event(&mut self) -> Option<(f64, Event)> {
+        let first = loop {
+            let queued = self.queue.pop()?;
+            if self.is_valid(&queued) {
+                break queued;
+            }
+        };
+        // 同时发生时先处理边事件
+        if let Event::Split(_, _) = first.event {
+            let mut held = vec![];
+            let mut edge = None;
+            while self
+                .queue
+                .peek()
+                .is_some_and(|queued| queued.time <= first.time + EPSILON)
+            {
+                let queued = self.queue.pop().unwrap();
+                if !self.is_valid(&queued) {
+                    continue;
+                }
+                if let Event::Edge(_) = queued.event {
+                    edge = Some(queued);
+                    break;
+                }
+                held.push(queued);
+            }
+            if let Some(edge) = edge {
+                held.push(first);
+                self.queue.extend(held);
+                return Some((edge.time, edge.event));
+            }
+            self.queue.extend(held);
+        }
+        Some((first.time, first.event))
+    }
+
+    // 只剩两个顶点的环直接连接后结束
+    fn close_if_degenerate(&mut self, v: usize, t: f64) {
+        if !self.vertices[v].active || self.cycle_len(v) > 2 {
+            return;
+        }
+        let w = self.vertices[v].next;
+        let (a, b) = (self.settle(v, t), self.settle(w, t));
+        self.add_arc(a, b);
+        self.vertices[v].active = false;
+        self.vertices[w].active = false;
+    }
+
+    fn process_edge(&mut self, u: usize, t: f64) {
+        let w = self.vertices[u].next;
+        let (pu, pw) = (self.position(u, t), self.position(w, t));
+        let pt = Point2::new((pu.x() + pw.x()) / 2., (pu.y() + pw.y()) / 2.);
+        let node = self.add_node(pt.clone(), t);
+        let (nu, nw) = (self.vertices[u].node, self.vertices[w].node);
+        self.add_arc(nu, node);
+        self.add_arc(nw, node);
+        self.vertices[u].active = false;
+        self.vertices[w].active = false;
+
+        if self.cycle_len(u) == 3 {
+            // 三角形收缩, 第三个顶点也到达 (或在退化时连到) 同一点
+            let x = self.vertices[w].next;
+            let nx = self.settle(x, t);
+            self.add_arc(nx, node);
+            self.vertices[x].active = false;
+            return;
+        }
+        let (prev, next) = (self.vertices[u].prev, self.vertices[w].next);
+        let z = self.push_vertex(
+            pt,
+            t,
+            self.vertices[u].in_edge,
+            self.vertices[w].out_edge,
+            node,
+        );
+        self.link(prev, z);
+        self.link(z, next);
+        self.close_if_degenerate(z, t);
+        self.update(prev, t);
+        self.update(z, t);
+    }
+
+    fn process_split(&mut self, v: usize, a: usize, t: f64) {
+        let hit = self.position(v, t);
+        let node = self.add_node(hit.clone(), t);
+        let nv = self.vertices[v].node;
+        self.add_arc(nv, node);
+        self.vertices[v].active = false;
+
+        let b = self.vertices[a].next;
+        let e = self.vertices[a].out_edge;
+        let (prev, next) = (self.vertices[v].prev, self.vertices[v].next);
+        let v1 = self.push_vertex(hit.clone(), t, self.vertices[v].in_edge, e, node);
+        let v2 = self.push_vertex(hit, t, e, self.vertices[v].out_edge, node);
+        self.link(prev, v1);
+        self.link(v1, b);
+        self.link(a, v2);
+        self.link(v2, next);
+        self.close_if_degenerate(v1, t);
+        self.close_if_degenerate(v2, t);
+        for &x in [prev, a, v1, v2].iter() {
+            self.update(x, t);
+        }
+    }
+}
+
+// 波前模拟: 事件放在优先队列中, 每次取出最早的边事件或分裂事件处理,
+// 处理后只重新计算链接改变的顶点相关的事件, 每次 O(n), 初始化 O(n^2)
+// 边事件至少减少一个顶点, 分裂事件不超过凹顶点数, 事件数为 O(n)
+// 外环与洞的波前相撞时两个环合并为一个
+fn straight_skeleton(rings: &[Vec<Point2>]) -> Option<Skeleton> {
+    let mut wave = Wavefront::new(rings);
+    let n = wave.vertices.len();
+    let mut t = 0.;
+    for _ in 0..4 * n + 16 {
+        match wave.next_event() {
+            Some((time, Event::Edge(u))) => {
+                t = time.max(t);
+                wave.process_edge(u, t);
+            }
+            Some((time, Event::Split(v, a))) => {
+                t = time.max(t);
+                wave.process_split(v, a, t);
+            }
+            None => return Some(wave.skeleton),
+        }
+    }
+    // 超过事件数上限, 结果不完整
+    None
+}
+
+fn medial_axis(rings: &[Vec<Point2>], spacing: f64) -> Skeleton {
+    if rings.is_empty() {
+        return Skeleton::default();
+    }
+    let dense: Vec<LineString<f64>> = rings
+        .iter()
+        .map(|ring| {
+            let n = ring.len();
+            let mut pts = vec![];
+            for i in 0..n {
+                let (a, b) = (&ring[i], &ring[(i + 1) % n]);
+                let steps = if spacing > 0. {
+                    (a.euclid_distance(b) / spacing).ceil().max(1.) as usize
+                } else {
+                    1
+                };
+                for k in 0..steps {
+                    let s = k as f64 / steps as f64;
+                    pts.push(Point::new(
+                        a.x() + (b.x() - a.x()) * s,
+                        a.y() + (b.y() - a.y()) * s,
+                    ));
+                }
+            }
+            LineString::from(pts)
+        })
+        .collect();
+    let mesh = SinglePolygon::new(dense[0].clone(), dense[1..].to_vec()).delaunay();
+    let boundary: Vec<Vec<Point<f64>>> = rings
+        .iter()
+        .map(|ring| ring.iter().map(|pt| Point::new(pt.x(), pt.y())).collect())
+        .collect();
+
+    let pts: Vec<Point2> = mesh
+        .points()
+        .iter()
+        .map(|pt| Point2::new(pt.x(), pt.y()))
+        .collect();
+    let circles: Vec<Option<Circle>> = mesh
+        .triangles()
+        .iter()
+        .map(|tri| {
+            Circle::circumcircle(&pts[tri[0]], &pts[tri[1]], &pts[tri[2]])
+                .filter(|c| point_in_rings(&boundary, &Point::new(c.center().x(), c.center().y())))
+        })
+        .collect();
+
+    // 外心重合的相邻三角形合并为一个节点
+    let mut root: Vec<usize> = (0..circles.len()).collect();
+    fn find(root: &mut [usize], t: usize) -> usize {
+        let mut r = t;
+        while root[r] != r {
+            r = root[r];
+        }
+        root[t] = r;
+        r
+    }
+    for t in 0..circles.len() {
+        for &u in mesh.neighbors()[t].iter().flatten() {
+            if let (Some(a), Some(b)) = (&circles[t], &circles[u]) {
+                if a.center().almost_equal(b.center()) {
+                    let (rt, ru) = (find(&mut root, t), find(&mut root, u));
+                    root[rt] = ru;
+                }
+            }
+        }
+    }
+
+    let mut skeleton = Skeleton::default();
+    let mut index: HashMap<usize, usize> = HashMap::new();
+    let mut node_of = |t: usize, root: &mut Vec<usize>, skeleton: &mut Skeleton| {
+        let r = find(root, t);
+        *index.entry(r).or_insert_with(|| {
+            let circle = circles[r].as_ref().unwrap();
+            skeleton.nodes.push(circle.center().clone());
+            skeleton.heights.push(circle.radius());
+            skeleton.nodes.len() - 1
+        })
+    };
+    let mut arc_set = HashSet::new();
+    for t in 0..circles.len() {
+        if circles[t].is_none() {
+            continue;
+        }
+        for &u in mesh.neighbors()[t].iter().flatten() {
+            if circles[u].is_none() {
+                continue;
+            }
+            let (a, b) = (
+                node_of(t, &mut root, &mut skeleton),
+                node_of(u, &mut root, &mut skeleton),
+            );
+            if a != b && arc_set.insert((a.min(b), a.max(b))) {
+                skeleton.arcs.push((a, b));
+            }
+        }
+    }
+    skeleton
+}
+
+#[cfg(test)]
+mod test {
+    use super::StraightSkeleton;
+    use crate::base::Point2;
+    use crate::geometric::{FromPoint, FromPoints, SimplePolygon, SinglePolygon};
+
+    #[test]
+    fn test_straight_skeleton() {
+        // 矩形: 4 条到角的斜边, 加中间的脊
+        let rect = SimplePolygon::from_points(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (0.0, 2.0)]);
+        let skeleton = rect.straight_skeleton().unwrap();
+        assert_eq!(skeleton.len(), 5);
+        assert!((skeleton.length() - (4. * 2f64.sqrt() + 2.)).abs() < 1e-6);
+        assert!(skeleton.heights.iter().all(|&h| h <= 1. + 1e-9));
+        // 剪掉通向角的短枝后只剩中线
+        let spine = skeleton.prune(2.);
+        assert_eq!(spine.len(), 1);
+        let (a, b) = spine.arcs[0];
+        let mut ends = vec![spine.nodes[a].clone(), spine.nodes[b].clone()];
+        ends.sort_by(|p, q| p.partial_cmp(q).unwrap());
+        assert_eq!(ends, vec![Point2::new(1., 1.), Point2::new(3., 1.)]);
+
+        // 两臂宽度不同的 L 形, 一个凹顶点, 无退化时弧的个数为 2n - 3
+        let l_shape = SimplePolygon::from_points(vec![
+            (0.0, 0.0),
+            (6.0, 0.0),
+            (6.0, 1.0),
+            (2.0, 1.0),
+            (2.0, 6.0),
+            (0.0, 6.0),
+        ]);
+        let skeleton = l_shape.straight_skeleton().unwrap();
+        assert_eq!(skeleton.len(), 9);
+        assert!(skeleton.heights.iter().all(|&h| h <= 1. + 1e-9));
+
+        // 带洞: 方环的骨架是一圈, 加上连向 8 个角的斜边
+        let ring = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (6.0, 0.0), (6.0, 6.0), (0.0, 6.0)],
+            vec![vec![(2.0, 2.0), (2.0, 4.0), (4.0, 4.0), (4.0, 2.0)]],
+        );
+        let skeleton = ring.straight_skeleton().unwrap();
+        assert_eq!(skeleton.len(), 12);
+        assert!((skeleton.length() - (8. * 2f64.sqrt() + 16.)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_medial_axis() {
+        let rect =
+            SimplePolygon::from_points(vec![(0.0, 0.0), (10.0, 0.0), (10.0, 2.0), (0.0, 2.0)]);
+        let axis = rect.medial_axis(0.25).prune(1.5);
+        assert!(!axis.is_empty());
+        for &(a, b) in axis.arcs.iter() {
+            // 中间部分的弧都在中线 y = 1 上
+            for &k in [a, b].iter() {
+                let pt = &axis.nodes[k];
+                if pt.x() > 1.5 && pt.x() < 8.5 {
+                    assert!((pt.y() - 1.).abs() < 1e-6, "{}", pt);
+                    assert!((axis.heights[k] - 1.).abs() < 0.05);
+                }
+            }
+        }
+        assert!(axis.length() > 7.5);
+    }
+}
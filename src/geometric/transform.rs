@@ -0,0 +1,108 @@
+use super::{ConvexPolygon, Orientation, SimplePolygon, SinglePolygon};
+use crate::base::{Point2, Transform, Transform2};
+use geo::{LineString, Polygon};
+
+fn transform_line(line: &LineString<f64>, t: &Transform2) -> LineString<f64> {
+    line.points_iter()
+        .map(|pt| {
+            let res = t.apply(&Point2::new(pt.x(), pt.y()));
+            (res.x(), res.y())
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+fn transform_polygon(pol: &Polygon<f64>, t: &Transform2) -> Polygon<f64> {
+    Polygon::new(
+        transform_line(pol.exterior(), t),
+        pol.interiors()
+            .iter()
+            .map(|line| transform_line(line, t))
+            .collect(),
+    )
+}
+
+// 带镜像的变换会让环反向, 这里再反回来, 保持原来的方向
+fn keep_orientation<T: Orientation>(res: T, t: &Transform2) -> T {
+    if t.determinant() < 0. {
+        res.reverse()
+    } else {
+        res
+    }
+}
+
+impl Transform for SimplePolygon {
+    fn transform(&self, t: &Transform2) -> Self {
+        let res = SimplePolygon {
+            _base_polygon: transform_polygon(&self._base_polygon, t),
+        };
+        keep_orientation(res, t)
+    }
+}
+
+impl Transform for SinglePolygon {
+    fn transform(&self, t: &Transform2) -> Self {
+        let res = SinglePolygon {
+            _base_polygon: transform_polygon(&self._base_polygon, t),
+        };
+        keep_orientation(res, t)
+    }
+}
+
+// 仿射变换保持凸性
+impl Transform for ConvexPolygon {
+    fn transform(&self, t: &Transform2) -> Self {
+        let res = ConvexPolygon {
+            _base_polygon: transform_polygon(&self._base_polygon, t),
+        };
+        keep_orientation(res, t)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::base::{AlmostEqual, Point2, Transform, Transform2, Vector2};
+    use crate::geometric::{
+        ConvexPolygon, FromPoints, Orientation, PointsConvexHull, SinglePolygon,
+    };
+
+    #[test]
+    fn test_transform_polygon() {
+        let pts: Vec<Point2> = vec![(0., 0.), (3., 0.), (4., 2.), (1., 3.), (2., 1.)]
+            .into_iter()
+            .map(|(x, y)| Point2::new(x, y))
+            .collect();
+        let hull: ConvexPolygon = pts.convex_hull().polygon;
+        // 带镜像和错切, 结果仍是逆时针的凸多边形
+        let t = Transform2::identity()
+            .scale(-2., 1.)
+            .shear(0.5, 0.)
+            .rotate(0.7)
+            .translate(3., 1.);
+        let res = hull.transform(&t);
+        assert!(res.is_ccw());
+        assert!((res.area() - hull.area() * t.determinant().abs()).abs() < 1e-9);
+        let moved: Vec<Point2> = res
+            .vertices()
+            .iter()
+            .map(|pt| Point2::new(pt.x(), pt.y()))
+            .collect();
+        assert_eq!(
+            moved.convex_hull().polygon.vertices().len(),
+            hull.vertices().len()
+        );
+        assert!((res.transform(&t.inverse().unwrap()).area() - hull.area()).abs() < 1e-9);
+
+        let pol = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)],
+            vec![vec![(1.0, 1.0), (1.0, 2.0), (2.0, 2.0), (2.0, 1.0)]],
+        );
+        let res = pol.translate(&Vector2::new(1., 2.));
+        assert!((res.area() - pol.area()).abs() < 1e-9);
+        let res = pol.transform(&Transform2::scaling(1., -1.));
+        assert_eq!(res.is_ccw(), pol.is_ccw());
+        let res = pol.rotate_about(&Point2::new(2., 2.), std::f64::consts::PI);
+        assert!(Point2::new(res.vertices()[0].x(), res.vertices()[0].y())
+            .almost_equal(&Point2::new(4., 4.)));
+    }
+}
@@ -0,0 +1,373 @@
+use super::utils::{cross, orientation, signed_area, to_point_list};
+use super::{ConvexPolygon, SimplePolygon, SinglePolygon};
+use geo::{LineString, Point};
+use std::cmp::Ordering;
+
+// 三角剖分, 返回顶点索引
+// 索引对应 vertices() 后依次接上各个洞的 vertices(), 闭合点不会被引用
+pub trait Triangulate {
+    fn triangulate(&self) -> Vec<[usize; 3]>;
+}
+
+impl Triangulate for SimplePolygon {
+    fn triangulate(&self) -> Vec<[usize; 3]> {
+        triangulate_lines(&[self._base_polygon.exterior()])
+    }
+}
+
+impl Triangulate for ConvexPolygon {
+    fn triangulate(&self) -> Vec<[usize; 3]> {
+        triangulate_lines(&[self._base_polygon.exterior()])
+    }
+}
+
+impl Triangulate for SinglePolygon {
+    fn triangulate(&self) -> Vec<[usize; 3]> {
+        let mut lines = vec![self._base_polygon.exterior()];
+        lines.extend(self._base_polygon.interiors().iter());
+        triangulate_lines(&lines)
+    }
+}
+
+impl SinglePolygon {
+    // vertices() 后依次接上各个洞的 vertices(), 与 triangulate() 的索引对应
+    pub fn all_vertices(&self) -> Vec<Point<f64>> {
+        let mut pts = self.vertices();
+        for line in self._base_polygon.interiors().iter() {
+            pts.extend(to_point_list(line));
+        }
+        pts
+    }
+}
+
+fn triangulate_lines(lines: &[&LineString<f64>]) -> Vec<[usize; 3]> {
+    let mut rings = vec![];
+    let mut offsets = vec![];
+    let mut offset = 0;
+    for line in lines.iter() {
+        let pts = to_point_list(line);
+        offsets.push(offset);
+        offset += pts.len();
+        let mut ring = pts;
+        if ring.len() > 1 && ring[0] == ring[ring.len() - 1] {
+            ring.pop();
+        }
+        rings.push(ring);
+    }
+
+    // ear_clip 的索引中各环不含闭合点
+    let mut starts = vec![];
+    let mut start = 0;
+    for ring in rings.iter() {
+        starts.push(start);
+        start += ring.len();
+    }
+    let to_closed = |i: usize| {
+        let k = match starts.binary_search(&i) {
+            Ok(k) => k,
+            Err(k) => k - 1,
+        };
+        offsets[k] + i - starts[k]
+    };
+    ear_clip(&rings)
+        .into_iter()
+        .map(|tri| [to_closed(tri[0]), to_closed(tri[1]), to_closed(tri[2])])
+        .collect()
+}
+
+// 耳切法三角剖分, rings[0] 为外环, 其余为洞, 各环不含闭合点
+// 洞按最右顶点从右到左通过桥边接入外环 (Eberly), 再对合并后的环切耳
+// 返回的索引对应各环依次排列后的位置, 三角形均为逆时针
+pub(crate) fn ear_clip(rings: &[Vec<Point<f64>>]) -> Vec<[usize; 3]> {
+    let mut pts: Vec<Point<f64>> = vec![];
+    let mut seqs: Vec<Vec<usize>> = vec![];
+    for (k, ring) in rings.iter().enumerate() {
+        let start = pts.len();
+        pts.extend(ring.iter().cloned());
+        let mut seq: Vec<usize> = (start..pts.len()).collect();
+        seq.dedup_by(|a, b| pts[*a] == pts[*b]);
+        let area = signed_area(ring);
+        // 外环逆时针, 洞顺时针
+        if (k == 0 && area < 0.) || (k > 0 && area > 0.) {
+            seq.reverse();
+        }
+        seqs.push(seq);
+    }
+    if seqs.is_empty() || seqs[0].len() < 3 {
+        return vec![];
+    }
+
+    let mut outer = seqs[0].clone();
+    let mut holes: Vec<Vec<usize>> = seqs[1..]
+        .iter()
+        .filter(|seq| seq.len() >= 3)
+        .cloned()
+        .collect();
+    let max_x = |seq: &Vec<usize>| {
+        seq.iter()
+            .map(|&i| pts[i].x())
+            .fold(f64::NEG_INFINITY, f64::max)
+    };
+    holes.sort_by(|a, b| max_x(b).partial_cmp(&max_x(a)).unwrap_or(Ordering::Equal));
+    for hole in holes.iter() {
+        bridge_hole(&pts, &mut outer, hole);
+    }
+    clip_ears(&pts, &outer)
+}
+
+// 从洞的最右顶点 M 向 +x 方向找外环上可见的顶点 P, 插入桥边 P-M
+fn bridge_hole(pts: &[Point<f64>], outer: &mut Vec<usize>, hole: &[usize]) {
+    let start = (0..hole.len())
+        .max_by(|&a, &b| {
+            let (pa, pb) = (&pts[hole[a]], &pts[hole[b]]);
+            pa.x()
+                .partial_cmp(&pb.x())
+                .unwrap_or(Ordering::Equal)
+                .then(pb.y().partial_cmp(&pa.y()).unwrap_or(Ordering::Equal))
+        })
+        .unwrap_or(0);
+    let m = pts[hole[start]];
+    let n = outer.len();
+
+    // 射线与外环的最近交点, best 为外环中 P 的位置
+    let mut best: Option<(f64, usize)> = None;
+    for k in 0..n {
+        let (ka, kb) = (k, (k + 1) % n);
+        let (a, b) = (&pts[outer[ka]], &pts[outer[kb]]);
+        if (a.y() - m.y()) * (b.y() - m.y()) > 0. {
+            continue;
+        }
+        let (x, pos) = if a.y() == b.y() {
+            if a.x() < b.x() {
+                (a.x(), ka)
+            } else {
+                (b.x(), kb)
+            }
+        } else {
+            let x = a.x() + (m.y() - a.y()) / (b.y() - a.y()) * (b.x() - a.x());
+            let pos = if a.y() == m.y() {
+                ka
+            } else if b.y() == m.y() || b.x() > a.x() {
+                kb
+            } else {
+                ka
+            };
+            (x, pos)
+        };
+        if x >= m.x() && best.map_or(true, |(bx, _)| x < bx) {
+            best = Some((x, pos));
+        }
+    }
+    let (x, mut pos) = match best {
+        Some(res) => res,
+        None => return,
+    };
+
+    // 三角形 M, I, P 内的凹顶点会遮挡 P, 取与 +x 方向夹角最小的
+    let i_pt = Point::new(x, m.y());
+    let p = pts[outer[pos]];
+    if p != i_pt {
+        let mut best_cos = f64::NEG_INFINITY;
+        for k in 0..n {
+            let r = pts[outer[k]];
+            if k == pos || r == m {
+                continue;
+            }
+            let (prev, next) = (&pts[outer[(k + n - 1) % n]], &pts[outer[(k + 1) % n]]);
+            if orientation(prev, &r, next) >= 0 {
+                continue;
+            }
+            let inside = if cross(&m, &i_pt, &p) >= 0. {
+                cross(&m, &i_pt, &r) >= 0. && cross(&i_pt, &p, &r) >= 0. && cross(&p, &m, &r) >= 0.
+            } else {
+                cross(&m, &i_pt, &r) <= 0. && cross(&i_pt, &p, &r) <= 0. && cross(&p, &m, &r) <= 0.
+            };
+            if !inside {
+                continue;
+            }
+            let d = ((r.x() - m.x()).powi(2) + (r.y() - m.y()).powi(2)).sqrt();
+            let cos = (r.x() - m.x()) / d;
+            if cos > best_cos {
+                best_cos = cos;
+                pos = k;
+            }
+        }
+    }
+
+    // 同一顶点可能因之前的桥边出现多次, 选择 M 落在其内角中的那一个
+    let target = outer[pos];
+    for k in 0..n {
+        if outer[k] != target {
+            continue;
+        }
+        let (prev, v, next) = (
+            &pts[outer[(k + n - 1) % n]],
+            &pts[outer[k]],
+            &pts[outer[(k + 1) % n]],
+        );
+        let in_cone = if orientation(prev, v, next) >= 0 {
+            cross(prev, v, &m) >= 0. && cross(v, next, &m) >= 0.
+        } else {
+            cross(prev, v, &m) >= 0. || cross(v, next, &m) >= 0.
+        };
+        if in_cone {
+            pos = k;
+            break;
+        }
+    }
+
+    let mut merged = Vec::with_capacity(n + hole.len() + 2);
+    merged.extend_from_slice(&outer[..=pos]);
+    for k in 0..=hole.len() {
+        merged.push(hole[(start + k) % hole.len()]);
+    }
+    merged.push(outer[pos]);
+    merged.extend_from_slice(&outer[pos + 1..]);
+    *outer = merged;
+}
+
+// 对逆时针的环切耳
+// 找不到严格的耳时依次放宽: 先去掉共线顶点, 再强制切除
+// 强制切除只在输入退化 (自相交或数值误差) 时发生, 面积不为正的三角形直接丢弃, 不输出反向的三角形
+fn clip_ears(pts: &[Point<f64>], poly: &[usize]) -> Vec<[usize; 3]> {
+    let m = poly.len();
+    let mut res = vec![];
+    if m < 3 {
+        return res;
+    }
+    let mut prev: Vec<usize> = (0..m).map(|i| (i + m - 1) % m).collect();
+    let mut next: Vec<usize> = (0..m).map(|i| (i + 1) % m).collect();
+    let mut removed = vec![false; m];
+    let mut remaining = m;
+    let mut cur = 0;
+    let mut stall = 0;
+    let mut pass = 0;
+    while remaining > 3 {
+        let (p, n) = (prev[cur], next[cur]);
+        let (a, b, c) = (&pts[poly[p]], &pts[poly[cur]], &pts[poly[n]]);
+        let turn = orientation(a, b, c);
+        let clip = match pass {
+            0 => turn > 0 && is_ear(pts, poly, &removed, [p, cur, n]),
+            1 => turn == 0,
+            _ => true,
+        };
+        if clip {
+            if turn > 0 {
+                res.push([poly[p], poly[cur], poly[n]]);
+            }
+            removed[cur] = true;
+            next[p] = n;
+            prev[n] = p;
+            remaining -= 1;
+            cur = n;
+            stall = 0;
+            pass = 0;
+        } else {
+            cur = n;
+            stall += 1;
+            if stall >= remaining {
+                stall = 0;
+                pass += 1;
+            }
+        }
+    }
+    let (p, n) = (prev[cur], next[cur]);
+    if orientation(&pts[poly[p]], &pts[poly[cur]], &pts[poly[n]]) > 0 {
+        res.push([poly[p], poly[cur], poly[n]]);
+    }
+    res
+}
+
+// 三角形内 (含边界) 没有其他顶点, 与三角形顶点重合的桥边顶点除外
+fn is_ear(pts: &[Point<f64>], poly: &[usize], removed: &[bool], tri: [usize; 3]) -> bool {
+    let (a, b, c) = (&pts[poly[tri[0]]], &pts[poly[tri[1]]], &pts[poly[tri[2]]]);
+    for (k, &i) in poly.iter().enumerate() {
+        if removed[k] || tri.contains(&k) {
+            continue;
+        }
+        let q = &pts[i];
+        if q == a || q == b || q == c {
+            continue;
+        }
+        if orientation(a, b, q) >= 0 && orientation(b, c, q) >= 0 && orientation(c, a, q) >= 0 {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::Triangulate;
+    use crate::geometric::{FromPoint, FromPoints, SimplePolygon, SinglePolygon};
+    use geo::Point;
+
+    fn total_area(pts: &[Point<f64>], tris: &[[usize; 3]]) -> f64 {
+        tris.iter()
+            .map(|t| {
+                let (a, b, c) = (pts[t[0]], pts[t[1]], pts[t[2]]);
+                ((b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x())) / 2.
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_triangulate_simple_polygon() {
+        let pol = SimplePolygon::from_points(vec![
+            (0.0, 0.0),
+            (0.0, 4.0),
+            (1.0, 4.0),
+            (1.0, 1.0),
+            (4.0, 1.0),
+            (4.0, 0.0),
+        ]);
+        let tris = pol.triangulate();
+        assert_eq!(tris.len(), 4);
+        assert!((total_area(&pol.vertices(), &tris) - 7.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_triangulate_degenerate() {
+        // 边上有连续的共线点, 凹顶点处也有
+        let pol = SimplePolygon::from_points(vec![
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (2.0, 0.0),
+            (3.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 1.0),
+            (3.0, 1.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 2.0),
+            (1.0, 3.0),
+            (1.0, 4.0),
+            (0.0, 4.0),
+            (0.0, 3.0),
+            (0.0, 2.0),
+            (0.0, 1.0),
+        ]);
+        let pts = pol.vertices();
+        let tris = pol.triangulate();
+        assert!(tris.iter().all(|t| total_area(&pts, &[*t]) > 0.));
+        assert!((total_area(&pts, &tris) - 7.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_triangulate_with_holes() {
+        let pol = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (6.0, 0.0), (6.0, 6.0), (0.0, 6.0)],
+            vec![
+                vec![(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 2.0)],
+                vec![(3.0, 3.0), (5.0, 3.0), (5.0, 5.0), (3.0, 5.0)],
+            ],
+        );
+        let tris = pol.triangulate();
+        assert_eq!(tris.len(), 4 + 4 + 4 + 2 * 2 - 2);
+        assert!((total_area(&pol.all_vertices(), &tris) - 31.).abs() < 1e-9);
+        // 闭合点不被引用
+        assert!(tris
+            .iter()
+            .all(|t| t.iter().all(|&i| i != 4 && i != 9 && i != 14)));
+    }
+}
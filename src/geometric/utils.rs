@@ -1,4 +1,5 @@
-use geo::{CoordinateType, LineString, Point};
+use crate::base::{Point2, Segment2, EPSILON};
+use geo::{CoordinateType, LineString, Point, Polygon};
 
 
 pub(crate) fn to_point_list<T>(lines: &LineString<T>) -> Vec<Point<T>>
@@ -6,4 +7,140 @@ where
     T: CoordinateType,
 {
     lines.clone().into_points()
-}
\ No newline at end of file
+}
+
+// 环的各条边, 跳过长度为 0 的边
+pub(crate) fn ring_edges(lines: &LineString<f64>) -> impl Iterator<Item = Segment2> + '_ {
+    lines
+        .lines()
+        .filter(|line| line.start != line.end)
+        .map(|line| {
+            Segment2::new(
+                Point2::new(line.start.x, line.start.y),
+                Point2::new(line.end.x, line.end.y),
+            )
+        })
+}
+
+// 去掉闭合点的环
+pub(crate) fn to_ring(lines: &LineString<f64>) -> Vec<Point<f64>> {
+    let mut pts = to_point_list(lines);
+    if pts.len() > 1 && pts[0] == pts[pts.len() - 1] {
+        pts.pop();
+    }
+    pts
+}
+
+// 叉积 (a - o) x (b - o)
+pub(crate) fn cross(o: &Point<f64>, a: &Point<f64>, b: &Point<f64>) -> f64 {
+    (a.x() - o.x()) * (b.y() - o.y()) - (a.y() - o.y()) * (b.x() - o.x())
+}
+
+// 1: 逆时针, -1: 顺时针, 0: 共线
+pub(crate) fn orientation(o: &Point<f64>, a: &Point<f64>, b: &Point<f64>) -> i8 {
+    let c = cross(o, a, b);
+    if c > EPSILON {
+        1
+    } else if c < -EPSILON {
+        -1
+    } else {
+        0
+    }
+}
+
+// 共线时 pt 是否在 [a, b] 的包围盒内
+pub(crate) fn in_box(a: &Point<f64>, b: &Point<f64>, pt: &Point<f64>) -> bool {
+    pt.x() >= a.x().min(b.x()) - EPSILON
+        && pt.x() <= a.x().max(b.x()) + EPSILON
+        && pt.y() >= a.y().min(b.y()) - EPSILON
+        && pt.y() <= a.y().max(b.y()) + EPSILON
+}
+
+// 线段 p1p2 与 q1q2 是否相交 (含端点接触和共线重叠)
+pub(crate) fn segments_intersect(
+    p1: &Point<f64>,
+    p2: &Point<f64>,
+    q1: &Point<f64>,
+    q2: &Point<f64>,
+) -> bool {
+    let d1 = orientation(q1, q2, p1);
+    let d2 = orientation(q1, q2, p2);
+    let d3 = orientation(p1, p2, q1);
+    let d4 = orientation(p1, p2, q2);
+    if d1 * d2 < 0 && d3 * d4 < 0 {
+        return true;
+    }
+    (d1 == 0 && in_box(q1, q2, p1))
+        || (d2 == 0 && in_box(q1, q2, p2))
+        || (d3 == 0 && in_box(p1, p2, q1))
+        || (d4 == 0 && in_box(p1, p2, q2))
+}
+
+// 点到线段 ab 的距离, 退化线段时为到 a 的距离
+pub(crate) fn segment_distance(a: &Point<f64>, b: &Point<f64>, pt: &Point<f64>) -> f64 {
+    let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 > 0. {
+        (((pt.x() - a.x()) * dx + (pt.y() - a.y()) * dy) / len2).clamp(0., 1.)
+    } else {
+        0.
+    };
+    (a.x() + t * dx - pt.x()).hypot(a.y() + t * dy - pt.y())
+}
+
+// 有向面积, 逆时针为正
+pub(crate) fn signed_area(ring: &[Point<f64>]) -> f64 {
+    let n = ring.len();
+    let mut res = 0.;
+    for i in 0..n {
+        let (a, b) = (&ring[i], &ring[(i + 1) % n]);
+        res += a.x() * b.y() - b.x() * a.y();
+    }
+    res / 2.
+}
+
+// 外环面积减去洞的面积, 与环的方向无关
+// 布尔运算的结果外环逆时针、洞顺时针, 不能直接用 geo 的 area
+pub(crate) fn unsigned_area(pol: &Polygon<f64>) -> f64 {
+    pol.interiors()
+        .iter()
+        .fold(signed_area(&to_ring(pol.exterior())).abs(), |total, line| {
+            total - signed_area(&to_ring(line)).abs()
+        })
+}
+
+// 奇偶规则判断点是否在若干环围成的区域内, 边界上的点结果不确定
+pub(crate) fn point_in_rings(rings: &[Vec<Point<f64>>], pt: &Point<f64>) -> bool {
+    let mut inside = false;
+    for ring in rings.iter() {
+        let n = ring.len();
+        for i in 0..n {
+            let (a, b) = (&ring[i], &ring[(i + 1) % n]);
+            if (a.y() > pt.y()) != (b.y() > pt.y()) {
+                let x = a.x() + (pt.y() - a.y()) / (b.y() - a.y()) * (b.x() - a.x());
+                if pt.x() < x {
+                    inside = !inside;
+                }
+            }
+        }
+    }
+    inside
+}
+
+// 外环逆时针, 内环顺时针
+pub(crate) fn oriented_rings(pol: &Polygon<f64>) -> Vec<Vec<Point<f64>>> {
+    let mut rings = vec![];
+    let mut ex = to_ring(pol.exterior());
+    if signed_area(&ex) < 0. {
+        ex.reverse();
+    }
+    rings.push(ex);
+    for line in pol.interiors().iter() {
+        let mut hole = to_ring(line);
+        if signed_area(&hole) > 0. {
+            hole.reverse();
+        }
+        rings.push(hole);
+    }
+    rings
+}
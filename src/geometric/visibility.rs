@@ -0,0 +1,373 @@
+use super::utils::{oriented_rings, point_in_rings};
+use super::{SimplePolygon, SinglePolygon};
+use crate::base::{AlmostEqual, Linear, Point2, Segment2, Vector2, EPSILON};
+use geo::{LineString, Point, Polygon};
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::f64::consts::PI;
+
+// 从 pt 出发能看到的区域, 洞会遮挡视线
+// pt 在多边形外时返回 None; pt 在边界上时只看多边形内部一侧
+pub trait Visibility {
+    fn visibility(&self, pt: &Point2) -> Option<SimplePolygon>;
+}
+
+impl Visibility for SimplePolygon {
+    fn visibility(&self, pt: &Point2) -> Option<SimplePolygon> {
+        visibility_polygon(&self._base_polygon, pt)
+    }
+}
+
+impl Visibility for SinglePolygon {
+    fn visibility(&self, pt: &Point2) -> Option<SimplePolygon> {
+        visibility_polygon(&self._base_polygon, pt)
+    }
+}
+
+// 扫描线上的边, a -> b 绕观察点逆时针
+// 活动的边都与当前射线相交且互不交叉, 它们沿射线的远近关系在扫描过程中不变,
+// 所以可以直接作为 BTreeSet 的序, 离观察点近的在前
+#[derive(Clone, Debug)]
+struct SweepEdge {
+    id: usize,
+    a: Point2,
+    b: Point2,
+}
+
+// pt 在有向直线 a -> b 的哪一侧, 左侧为 1
+fn side(a: &Point2, b: &Point2, pt: &Point2) -> i8 {
+    let cross = b.from(a).exterior(&pt.from(a));
+    if cross > 0. {
+        1
+    } else if cross < 0. {
+        -1
+    } else {
+        0
+    }
+}
+
+impl SweepEdge {
+    // other 的两个端点在 self 的同一侧时返回那一侧, 共享端点不算
+    fn side_of(&self, other: &SweepEdge) -> i8 {
+        let (s1, s2) = (
+            side(&self.a, &self.b, &other.a),
+            side(&self.a, &self.b, &other.b),
+        );
+        match (s1, s2) {
+            (0, s) | (s, 0) => s,
+            (s1, s2) if s1 == s2 => s1,
+            _ => 0,
+        }
+    }
+}
+
+// 观察点总在每条边的左侧: other 整个在 self 右侧时 self 更近
+impl Ord for SweepEdge {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.id == other.id {
+            return Ordering::Equal;
+        }
+        match (self.side_of(other), other.side_of(self)) {
+            (-1, _) => Ordering::Less,
+            (1, _) => Ordering::Greater,
+            (_, -1) => Ordering::Greater,
+            (_, 1) => Ordering::Less,
+            // 共线的两条边不会同时挡在一条射线上
+            _ => self.id.cmp(&other.id),
+        }
+    }
+}
+
+impl PartialOrd for SweepEdge {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SweepEdge {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for SweepEdge {}
+
+struct Event {
+    angle: f64,
+    insert: bool,
+    id: usize,
+}
+
+// 射线 origin + t * dir 与边所在直线的交点; 射线过端点时直接取端点
+fn hit(edge: &SweepEdge, origin: &Point2, dir: &Vector2, through: Option<&Point2>) -> Point2 {
+    if let Some(pt) = through {
+        if *pt == edge.a || *pt == edge.b {
+            return pt.clone();
+        }
+    }
+    let e = edge.b.from(&edge.a);
+    let denom = dir.exterior(&e);
+    if denom.abs() < EPSILON * EPSILON {
+        // 边与射线平行 (视点与边共线), 取沿射线方向先到达的端点
+        let (da, db) = (edge.a.from(origin).dot(dir), edge.b.from(origin).dot(dir));
+        return if db >= 0. && (da < 0. || db < da) {
+            edge.b.clone()
+        } else {
+            edge.a.clone()
+        };
+    }
+    let t = edge.a.from(origin).exterior(&e) / denom;
+    origin.move_along(&(dir.clone() * t))
+}
+
+// 角度扫描, O(n log n)
+// 事件按相对起始方向的极角排序, 活动边集合的第一条即为当前方向上可见的边,
+// 可见边切换时记录射线与新旧两条边的交点
+fn visibility_polygon(pol: &Polygon<f64>, pt: &Point2) -> Option<SimplePolygon> {
+    let rings: Vec<Vec<Point2>> = oriented_rings(pol)
+        .iter()
+        .map(|ring| ring.iter().map(|p| Point2::new(p.x(), p.y())).collect())
+        .collect();
+
+    // 边界上的点只看内部一侧的张角, 从 start 逆时针转到 end
+    let mut window: Option<(Vector2, Vector2)> = None;
+    let mut segments = vec![];
+    for ring in rings.iter() {
+        let n = ring.len();
+        for i in 0..n {
+            let (prev, cur, next) = (&ring[(i + n - 1) % n], &ring[i], &ring[(i + 1) % n]);
+            if window.is_none() {
+                if cur.almost_equal(pt) {
+                    window = Some((next.from(pt), prev.from(pt)));
+                } else if Segment2::new(cur.clone(), next.clone()).contains(pt)
+                    && !next.almost_equal(pt)
+                {
+                    window = Some((next.from(pt), cur.from(pt)));
+                }
+            }
+            let seg = Segment2::new(cur.clone(), next.clone());
+            // 经过观察点的边和侧对观察点的边不遮挡视线
+            if seg.length() < EPSILON || seg.line().distance_to(pt) < EPSILON {
+                continue;
+            }
+            segments.push(seg);
+        }
+    }
+    if window.is_none() {
+        let inside = rings
+            .iter()
+            .map(|ring| ring.iter().map(|p| Point::new(p.x(), p.y())).collect())
+            .collect::<Vec<_>>();
+        if !point_in_rings(&inside, &Point::new(pt.x(), pt.y())) {
+            return None;
+        }
+    }
+    if segments.is_empty() {
+        return None;
+    }
+
+    // 整圈扫描时从最大的角度空隙中间开始, 起始方向上没有顶点
+    let (start, end) = match window.as_ref() {
+        Some((start, end)) => (start.clone(), Some(end.clone())),
+        None => {
+            let mut angles: Vec<f64> = segments
+                .iter()
+                .map(|seg| {
+                    let v = seg.start().from(pt);
+                    v.y().atan2(v.x())
+                })
+                .collect();
+            angles.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            let n = angles.len();
+            let (gap, from) = (0..n)
+                .map(|i| {
+                    let next = if i + 1 < n {
+                        angles[i + 1]
+                    } else {
+                        angles[0] + 2. * PI
+                    };
+                    (next - angles[i], angles[i])
+                })
+                .fold(
+                    (-1., 0.),
+                    |best, cur| if cur.0 > best.0 { cur } else { best },
+                );
+            let mid = from + gap / 2.;
+            (Vector2::new(mid.cos(), mid.sin()), None)
+        }
+    };
+    let angle_of = |v: &Vector2| {
+        let angle = start.exterior(v).atan2(start.dot(v));
+        if angle < 0. {
+            angle + 2. * PI
+        } else {
+            angle
+        }
+    };
+    let limit = end.as_ref().map_or(2. * PI, angle_of);
+    if limit <= 0. {
+        return None;
+    }
+
+    let mut edges = vec![];
+    let mut events = vec![];
+    let mut active = BTreeSet::new();
+    for (id, seg) in segments.iter().enumerate() {
+        let (a, b) = if seg.start().from(pt).exterior(&seg.end().from(pt)) > 0. {
+            (seg.start().clone(), seg.end().clone())
+        } else {
+            (seg.end().clone(), seg.start().clone())
+        };
+        let (angle_a, angle_b) = (angle_of(&a.from(pt)), angle_of(&b.from(pt)));
+        let edge = SweepEdge { id, a, b };
+        if angle_a > angle_b {
+            // 跨过起始方向: 开始时已在集合中, 转回 a 时再插入一次
+            active.insert(edge.clone());
+        }
+        events.push(Event {
+            angle: angle_a,
+            insert: true,
+            id,
+        });
+        events.push(Event {
+            angle: angle_b,
+            insert: false,
+            id,
+        });
+        edges.push(edge);
+    }
+    // 同一方向上先删除再插入
+    events.sort_by(|e1, e2| {
+        e1.angle
+            .partial_cmp(&e2.angle)
+            .unwrap_or(Ordering::Equal)
+            .then(e1.insert.cmp(&e2.insert))
+    });
+    let apply = |active: &mut BTreeSet<SweepEdge>, event: &Event| {
+        if event.insert {
+            active.insert(edges[event.id].clone());
+        } else {
+            active.remove(&edges[event.id]);
+        }
+    };
+
+    let mut res = vec![];
+    if window.is_some() {
+        res.push(pt.clone());
+    }
+    let mut i = 0;
+    // 起始方向上的事件先处理, 只有边界上的点会遇到
+    while i < events.len() && events[i].angle <= 0. {
+        apply(&mut active, &events[i]);
+        i += 1;
+    }
+    res.push(hit(active.iter().next()?, pt, &start, None));
+    while i < events.len() && events[i].angle < limit {
+        let angle = events[i].angle;
+        let through = if events[i].insert {
+            edges[events[i].id].a.clone()
+        } else {
+            edges[events[i].id].b.clone()
+        };
+        let dir = through.from(pt);
+        let old = active.iter().next().cloned();
+        while i < events.len() && events[i].angle - angle <= EPSILON * EPSILON {
+            apply(&mut active, &events[i]);
+            i += 1;
+        }
+        let new = active.iter().next().cloned();
+        if old != new {
+            if let Some(edge) = old.as_ref() {
+                res.push(hit(edge, pt, &dir, Some(&through)));
+            }
+            if let Some(edge) = new.as_ref() {
+                res.push(hit(edge, pt, &dir, Some(&through)));
+            }
+        }
+    }
+    if let Some(end) = end.as_ref() {
+        res.push(hit(active.iter().next()?, pt, end, None));
+    }
+    finish(res)
+}
+
+// 去掉重复点和共线点
+fn finish(mut ring: Vec<Point2>) -> Option<SimplePolygon> {
+    ring.dedup_by(|a, b| a.almost_equal(b));
+    while ring.len() > 1 && ring[0].almost_equal(&ring[ring.len() - 1]) {
+        ring.pop();
+    }
+    let mut k = 0;
+    while ring.len() >= 3 && k < ring.len() {
+        let len = ring.len();
+        let (prev, next) = (&ring[(k + len - 1) % len], &ring[(k + 1) % len]);
+        if Segment2::new(prev.clone(), next.clone())
+            .line()
+            .distance_to(&ring[k])
+            < EPSILON
+        {
+            ring.remove(k);
+            k = k.saturating_sub(1);
+        } else {
+            k += 1;
+        }
+    }
+    if ring.len() < 3 {
+        return None;
+    }
+    Some(SimplePolygon::new(LineString::from(
+        ring.iter().map(|pt| (pt.x(), pt.y())).collect::<Vec<_>>(),
+    )))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{hit, SweepEdge, Visibility};
+    use crate::base::{Point2, Vector2};
+    use crate::geometric::{FromPoint, FromPoints, SimplePolygon, SinglePolygon};
+
+    #[test]
+    fn test_visibility() {
+        let room = SinglePolygon::from_points(
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![vec![(4.0, 4.0), (4.0, 6.0), (6.0, 6.0), (6.0, 4.0)]],
+        );
+        let area = |pt: (f64, f64)| room.visibility(&Point2::new(pt.0, pt.1)).unwrap().area();
+        // 洞后面的梯形阴影面积为 30
+        assert!((area((2., 5.)) - 70.).abs() < 1e-9);
+        // 顶点和边上的点
+        assert!((area((0., 0.)) - 224. / 3.).abs() < 1e-9);
+        assert!((area((10., 10.)) - 224. / 3.).abs() < 1e-9);
+        assert!((area((0., 5.)) - 79.).abs() < 1e-9);
+        // 洞的边上只能看到洞外的一侧
+        assert!((area((4., 5.)) - 40.).abs() < 1e-9);
+        // 视点与洞的边共线
+        assert!((area((2., 4.)) - 72.).abs() < 1e-9);
+        assert!((area((8., 6.)) - 72.).abs() < 1e-9);
+        // 边与射线平行时取先到达的端点
+        let edge = SweepEdge {
+            id: 0,
+            a: Point2::new(6., 4.),
+            b: Point2::new(4., 4.),
+        };
+        let res = hit(&edge, &Point2::new(2., 4.), &Vector2::new(1., 0.), None);
+        assert_eq!(res, Point2::new(4., 4.));
+        assert!(room.visibility(&Point2::new(5., 5.)).is_none());
+        assert!(room.visibility(&Point2::new(11., 5.)).is_none());
+
+        let l_shape = SimplePolygon::from_points(vec![
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 4.0),
+            (0.0, 4.0),
+        ]);
+        let area = |pt: (f64, f64)| l_shape.visibility(&Point2::new(pt.0, pt.1)).unwrap().area();
+        assert!((area((0.5, 0.5)) - 7.).abs() < 1e-9);
+        assert!((area((3., 0.5)) - 4.125).abs() < 1e-9);
+        // 凹顶点上可以看到整个多边形
+        assert!((area((1., 1.)) - 7.).abs() < 1e-9);
+        assert!((area((4., 0.)) - (4. + 1. / 6.)).abs() < 1e-9);
+    }
+}
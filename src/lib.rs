@@ -1,8 +1,10 @@
 pub mod base;
 pub mod geometric;
 mod utils;
-use base::Color;
-use geometric::{ConvexHull, Draw, FromPoint, SimplePolygon};
+use base::{Color, Point2, Transform2};
+use geometric::{
+    ConvexHull, Draw, FromPoint, Locate, SimplePolygon, Simplify, SimplifyOptions, Visibility,
+};
 use std::cell::{Cell, RefCell};
 use std::f64;
 use std::rc::Rc;
@@ -21,6 +23,9 @@ extern "C" {
 
     #[wasm_bindgen(js_namespace = console)]
     fn log(s: &str);
+
+    #[wasm_bindgen(js_namespace = console)]
+    fn warn(s: &str);
 }
 
 #[wasm_bindgen]
@@ -36,6 +41,31 @@ pub fn greet() {
 
 #[wasm_bindgen]
 pub fn start() {
+    start_editor(None, Transform2::identity());
+}
+
+// 闭合笔画时按 tolerance 简化, 保持拓扑
+#[wasm_bindgen]
+pub fn start_with_simplify(tolerance: f64) {
+    start_editor(
+        Some(SimplifyOptions {
+            tolerance,
+            ..SimplifyOptions::default()
+        }),
+        Transform2::identity(),
+    );
+}
+
+// 视图变换: 世界坐标先缩放、旋转 (弧度), 再平移到画布上
+#[wasm_bindgen]
+pub fn start_with_view(dx: f64, dy: f64, scale: f64, angle: f64) {
+    let view = Transform2::scaling(scale, scale)
+        .rotate(angle)
+        .translate(dx, dy);
+    start_editor(None, view);
+}
+
+fn start_editor(simplify: Option<SimplifyOptions>, view: Transform2) {
     let document = web_sys::window().unwrap().document().unwrap();
     let canvas = document.get_element_by_id("base-canvas").unwrap();
     let canvas: web_sys::HtmlCanvasElement = canvas
@@ -60,19 +90,38 @@ pub fn start() {
     // let convex_pol = pol.convex_hull();
     // convex_pol.stroke(ctx.clone(), &color);
 
-    draw(ctx, &canvas).unwrap();
+    draw(ctx, &canvas, simplify, view).unwrap();
 }
 
 #[wasm_bindgen]
 pub fn clear_canvas(ctx: &web_sys::CanvasRenderingContext2d, w: f64, h: f64){
-    ctx.clear_rect(0., 0., w, h);
+    // 视图变换留在 context 上, 清除时临时换回画布坐标
+    ctx.save();
+    if ctx.set_transform(1., 0., 0., 1., 0., 0.).is_ok() {
+        ctx.clear_rect(0., 0., w, h);
+    }
+    ctx.restore();
+}
+
+// 鼠标的画布坐标换算到世界坐标
+fn to_world(event: &web_sys::MouseEvent, screen_to_world: &Transform2) -> Point2 {
+    screen_to_world.apply(&Point2::new(event.offset_x() as f64, event.offset_y() as f64))
 }
 
 pub fn draw(
     context: Rc<web_sys::CanvasRenderingContext2d>,
     canvas: &web_sys::HtmlCanvasElement,
+    simplify: Option<SimplifyOptions>,
+    view: Transform2,
 ) -> Result<(), JsValue> {
     let color = Color::from((125, 123, 0));
+    // 画布按视图变换绘制, 多边形都保存在世界坐标下
+    let [a, b, c, d, e, f] = view.to_canvas();
+    context.set_transform(a, b, c, d, e, f)?;
+    let screen_to_world = Rc::new(
+        view.inverse()
+            .ok_or_else(|| JsValue::from_str("The view transform is not invertible"))?,
+    );
 
     let mut start: (f64, f64) = (0., 0.);
     context.set_line_width(2.0);
@@ -81,18 +130,28 @@ pub fn draw(
 
     let mut pts: Vec<(f64, f64)> = vec![];
     let pts_ref = Rc::new(RefCell::new(pts));
+    // 已画好的多边形
+    let polygons: Rc<RefCell<Vec<SimplePolygon>>> = Rc::new(RefCell::new(vec![]));
     // context.set_line_dash(&JsValue::from_serde(&(4, 2)).unwrap())?;
     let pressed = Rc::new(Cell::new(false));
     {
         let context = context.clone();
         let pressed = pressed.clone();
         let pts = pts_ref.clone();
+        let polygons = polygons.clone();
+        let screen_to_world = screen_to_world.clone();
         let closure = Closure::wrap(Box::new(move |event: web_sys::MouseEvent| {
             if event.which() == 1 {
                 if !pressed.get() {
+                    let pt = to_world(&event, &screen_to_world);
+                    for (i, pol) in polygons.borrow().iter().enumerate() {
+                        if pol.contains(&pt) {
+                            log(&format!("Clicked polygon {}: {:?}", i, pol.locate(&pt)));
+                        }
+                    }
                     context.begin_path();
                     pressed.set(true);
-                    start = (event.offset_x() as f64, event.offset_y() as f64);
+                    start = (pt.x(), pt.y());
                     pts.borrow_mut().push(start);
                     context.move_to(start.0, start.1);
                 }
@@ -101,12 +160,31 @@ pub fn draw(
                 context.fill();
                 pressed.set(false);
                 log(&format!("The points is : {:?}", pts.borrow()));
-                let pol = SimplePolygon::from_points(&*pts.borrow());
-                log(&format!(
-                    "The polygon is : {}, area is: {}",
-                    pol,
-                    pol.area()
-                ));
+                match SimplePolygon::try_from_points(&*pts.borrow()) {
+                    Ok(mut pol) => {
+                        if let Some(options) = simplify.as_ref() {
+                            pol = pol.simplify_with(options);
+                        }
+                        log(&format!(
+                            "The polygon is : {}, area is: {}",
+                            pol,
+                            pol.area()
+                        ));
+                        polygons.borrow_mut().push(pol);
+                    }
+                    Err(err) => warn(&format!("Invalid polygon: {}", err)),
+                }
+                pts.borrow_mut().clear();
+            } else if event.which() == 2 && !pressed.get() {
+                // 中键: 叠加显示点击位置在所在多边形内的可见区域
+                let pt = to_world(&event, &screen_to_world);
+                for pol in polygons.borrow().iter() {
+                    if let Some(region) = pol.visibility(&pt) {
+                        region.fill(context.clone(), &Color::from((0, 160, 255, 0.3)));
+                    }
+                }
+                context.set_stroke_style_str(&color.to_string());
+                context.set_fill_style_str(&color.to_string());
             }
         }) as Box<dyn FnMut(_)>);
         canvas.add_event_listener_with_callback("mousedown", closure.as_ref().unchecked_ref())?;
@@ -117,9 +195,11 @@ pub fn draw(
         let context = context.clone();
         let pressed = pressed.clone();
         let pts = pts_ref.clone();
+        let screen_to_world = screen_to_world.clone();
         let closure = Closure::wrap(Box::new(move |event: web_sys::MouseEvent| {
             if pressed.get() {
-                let pt = (event.offset_x() as f64, event.offset_y() as f64);
+                let pt = to_world(&event, &screen_to_world);
+                let pt = (pt.x(), pt.y());
                 context.line_to(pt.0, pt.1);
                 pts.borrow_mut().push(pt);
                 context.stroke();